@@ -18,7 +18,6 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 struct Palette {
-    #[allow(dead_code)]
     version: String,
     #[serde(flatten)]
     flavors: HashMap<String, Flavor>,
@@ -103,6 +102,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         make_color_name_display_impl_tokens(sample_flavor),
         make_color_name_identifier_impl_tokens(sample_flavor),
         make_color_name_fromstr_impl_tokens(sample_flavor),
+        make_color_name_all_impl_tokens(sample_flavor),
     ];
     let ansi_color_tokens = [
         make_ansi_color_name_enum_tokens(sample_flavor),
@@ -110,6 +110,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         make_ansi_color_name_display_impl_tokens(sample_flavor),
         make_ansi_color_name_identifier_impl_tokens(sample_flavor),
         make_ansi_color_name_fromstr_impl_tokens(sample_flavor),
+        make_ansi_color_name_all_impl_tokens(sample_flavor),
     ];
     let ansi_color_pair_tokens = [
         make_ansi_color_pair_name_enum_tokens(sample_flavor),
@@ -117,8 +118,16 @@ fn main() -> Result<(), Box<dyn Error>> {
         make_ansi_color_pair_name_display_impl_tokens(sample_flavor),
         make_ansi_color_pair_name_identifier_impl_tokens(sample_flavor),
         make_ansi_color_pair_name_fromstr_impl_tokens(sample_flavor),
+        make_ansi_color_pair_name_all_impl_tokens(sample_flavor),
+    ];
+    let accent_tokens = [
+        make_accent_name_enum_tokens(sample_flavor),
+        make_accent_name_impl_tokens(sample_flavor),
+    ];
+    let palette_tokens = [
+        make_palette_const_tokens(&palette),
+        make_color_macro_tokens(&palette),
     ];
-    let palette_tokens = [make_palette_const_tokens(&palette)];
 
     let ast = syn::parse2(
         [
@@ -126,6 +135,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             &color_tokens[..],
             &ansi_color_tokens[..],
             &ansi_color_pair_tokens[..],
+            &accent_tokens[..],
             &palette_tokens[..],
         ]
         .concat()
@@ -183,6 +193,10 @@ fn colors_in_order(flavor: &Flavor) -> std::vec::IntoIter<(&String, &Color)> {
         .sorted_by(|(_, a), (_, b)| a.order.cmp(&b.order))
 }
 
+fn accents_in_order(flavor: &Flavor) -> impl Iterator<Item = (&String, &Color)> {
+    colors_in_order(flavor).filter(|(_, color)| color.accent)
+}
+
 fn ansi_color_pairs_in_order(
     flavor: &Flavor,
 ) -> std::vec::IntoIter<(&String, &AnsiColorPair, String, String)> {
@@ -382,7 +396,9 @@ fn make_color_name_enum_tokens(sample_flavor: &Flavor) -> TokenStream {
     });
     quote! {
         /// Enum of all named Catppuccin colors. Can be used to index into a [`FlavorColors`].
-        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+        ///
+        /// Ordered by each color's [`order`](ColorName::order) in the palette.
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum ColorName {
             #(#variants),*
@@ -402,7 +418,9 @@ fn make_ansi_color_name_enum_tokens(sample_flavor: &Flavor) -> TokenStream {
     });
     quote! {
         /// Enum of all named ANSI colors. Can be used to index into a [`FlavorAnsiColors`]
-        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+        ///
+        /// Ordered by each color's ANSI [`code`](AnsiColorName::code).
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum AnsiColorName {
             #(#variants),*
@@ -421,7 +439,9 @@ fn make_ansi_color_pair_name_enum_tokens(sample_flavor: &Flavor) -> TokenStream
     });
     quote! {
         /// Enum of all ANSI color pairs. Can be used to index into a [`FlavorAnsiColorPairs`].
-        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+        ///
+        /// Ordered by each pair's order in the palette.
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum AnsiColorPairName {
             #(#variants),*
@@ -438,7 +458,22 @@ fn make_color_name_index_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
         }
     });
     let second = first.clone();
+    let third = colors_in_order(sample_flavor).map(|(identifier, _)| {
+        let variant = format_ident!("{}", titlecase(identifier));
+        let ident = format_ident!("{}", identifier);
+        quote! {
+            ColorName::#variant => &mut self.#ident
+        }
+    });
     quote! {
+        impl IndexMut<ColorName> for FlavorColors {
+            fn index_mut(&mut self, index: ColorName) -> &mut Self::Output {
+                match index {
+                    #(#third),*
+                }
+            }
+        }
+
         impl Index<ColorName> for FlavorColors {
             type Output = Color;
 
@@ -473,7 +508,22 @@ fn make_ansi_color_name_index_impl_tokens(sample_flavor: &Flavor) -> TokenStream
         }
     });
     let second = first.clone();
+    let third = ansi_colors_in_order(sample_flavor).map(|(identifier, color)| {
+        let variant = format_ident!("{}", remove_whitespace(&color.name));
+        let ident = format_ident!("{}", identifier);
+        quote! {
+            AnsiColorName::#variant => &mut self.#ident
+        }
+    });
     quote! {
+        impl IndexMut<AnsiColorName> for FlavorAnsiColors {
+            fn index_mut(&mut self, index: AnsiColorName) -> &mut Self::Output {
+                match index {
+                    #(#third),*
+                }
+            }
+        }
+
         impl Index<AnsiColorName> for FlavorAnsiColors {
             type Output = AnsiColor;
 
@@ -686,68 +736,332 @@ fn make_ansi_color_pair_name_identifier_impl_tokens(sample_flavor: &Flavor) -> T
 }
 
 fn make_color_name_fromstr_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
-    let match_arms = colors_in_order(sample_flavor)
-        .map(|(identifier, _)| {
-            let variant = format_ident!("{}", titlecase(identifier));
-            quote! {
-                #identifier => Ok(Self::#variant)
-            }
-        })
-        .collect::<Vec<_>>();
+    let identifiers = colors_in_order(sample_flavor).map(|(identifier, _)| {
+        let variant = format_ident!("{}", titlecase(identifier));
+        quote! {
+            (#identifier, Self::#variant)
+        }
+    });
     quote! {
+        impl ColorName {
+            /// Parse a name like [`FromStr`](core::str::FromStr), but with a
+            /// suggestion of a similar name in the error if parsing fails.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the input is not a name of this kind.
+            pub fn parse_with_suggestion(s: &str) -> Result<Self, UnknownColorNameError> {
+                crate::parse::parse_name(
+                    s,
+                    &[#(#identifiers),*],
+                    crate::parse::COLOR_NAME_ALIASES,
+                )
+                .map_err(|suggestion| UnknownColorNameError { suggestion })
+            }
+        }
+
         impl core::str::FromStr for ColorName {
             type Err = ParseColorNameError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    #(#match_arms),*,
-                    _ => Err(ParseColorNameError),
-                }
+                Self::parse_with_suggestion(s).map_err(ParseColorNameError::from)
             }
         }
     }
 }
 
 fn make_ansi_color_name_fromstr_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
-    let match_arms = ansi_colors_in_order(sample_flavor)
-        .map(|(identifier, color)| {
-            let variant = format_ident!("{}", remove_whitespace(&color.name));
-            quote! {
-                #identifier => Ok(Self::#variant)
-            }
-        })
-        .collect::<Vec<_>>();
+    let identifiers = ansi_colors_in_order(sample_flavor).map(|(identifier, color)| {
+        let variant = format_ident!("{}", remove_whitespace(&color.name));
+        quote! {
+            (#identifier, Self::#variant)
+        }
+    });
     quote! {
+        impl AnsiColorName {
+            /// Parse a name like [`FromStr`](core::str::FromStr), but with a
+            /// suggestion of a similar name in the error if parsing fails.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the input is not a name of this kind.
+            pub fn parse_with_suggestion(s: &str) -> Result<Self, UnknownColorNameError> {
+                crate::parse::parse_name(
+                    s,
+                    &[#(#identifiers),*],
+                    crate::parse::ANSI_COLOR_NAME_ALIASES,
+                )
+                .map_err(|suggestion| UnknownColorNameError { suggestion })
+            }
+        }
+
         impl core::str::FromStr for AnsiColorName {
             type Err = ParseColorNameError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    #(#match_arms),*,
-                    _ => Err(ParseColorNameError),
-                }
+                Self::parse_with_suggestion(s).map_err(ParseColorNameError::from)
             }
         }
     }
 }
 
 fn make_ansi_color_pair_name_fromstr_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
-    let match_arms = ansi_color_pairs_in_order(sample_flavor)
-        .map(|(identifier, ..)| {
-            let variant = format_ident!("{}", titlecase(identifier));
-            quote! {
-                #identifier => Ok(Self::#variant)
+    let identifiers = ansi_color_pairs_in_order(sample_flavor).map(|(identifier, ..)| {
+        let variant = format_ident!("{}", titlecase(identifier));
+        quote! {
+            (#identifier, Self::#variant)
+        }
+    });
+    quote! {
+        impl AnsiColorPairName {
+            /// Parse a name like [`FromStr`](core::str::FromStr), but with a
+            /// suggestion of a similar name in the error if parsing fails.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the input is not a name of this kind.
+            pub fn parse_with_suggestion(s: &str) -> Result<Self, UnknownColorNameError> {
+                crate::parse::parse_name(
+                    s,
+                    &[#(#identifiers),*],
+                    crate::parse::ANSI_COLOR_PAIR_NAME_ALIASES,
+                )
+                .map_err(|suggestion| UnknownColorNameError { suggestion })
             }
-        })
+        }
+
+        impl core::str::FromStr for AnsiColorPairName {
+            type Err = ParseColorNameError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse_with_suggestion(s).map_err(ParseColorNameError::from)
+            }
+        }
+    }
+}
+
+fn make_color_name_all_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
+    let variants = colors_in_order(sample_flavor)
+        .map(|(identifier, _)| format_ident!("{}", titlecase(identifier)))
         .collect::<Vec<_>>();
+    let count = variants.len();
+    let orders = colors_in_order(sample_flavor).map(|(_, color)| color.order);
+    let accent_variants = accents_in_order(sample_flavor)
+        .map(|(identifier, _)| format_ident!("{}", titlecase(identifier)));
     quote! {
-        impl core::str::FromStr for AnsiColorPairName {
+        impl ColorName {
+            /// All color names, in order.
+            ///
+            /// Example:
+            ///
+            /// ```rust
+            /// use catppuccin::ColorName;
+            ///
+            /// assert_eq!(ColorName::ALL.len(), 26);
+            /// assert!(ColorName::ALL.is_sorted());
+            /// assert_eq!(ColorName::ALL[0], ColorName::Rosewater);
+            /// ```
+            pub const ALL: [Self; #count] = [#(Self::#variants),*];
+
+            /// Get the color's order in the palette spec.
+            #[must_use]
+            pub const fn order(&self) -> u32 {
+                match self {
+                    #(Self::#variants => #orders),*
+                }
+            }
+
+            /// Whether the color is an accent color. See [`Color::accent`].
+            ///
+            /// Example:
+            ///
+            /// ```rust
+            /// use catppuccin::ColorName;
+            ///
+            /// assert!(ColorName::Mauve.is_accent());
+            /// assert!(!ColorName::Crust.is_accent());
+            /// ```
+            #[must_use]
+            pub const fn is_accent(&self) -> bool {
+                matches!(self, #(Self::#accent_variants)|*)
+            }
+        }
+    }
+}
+
+fn make_ansi_color_name_all_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
+    let variants = ansi_colors_in_order(sample_flavor)
+        .map(|(_, color)| format_ident!("{}", remove_whitespace(&color.name)))
+        .collect::<Vec<_>>();
+    let count = variants.len();
+    let codes = ansi_colors_in_order(sample_flavor)
+        .map(|(_, color)| color.code)
+        .collect::<Vec<_>>();
+    quote! {
+        impl AnsiColorName {
+            /// All ANSI color names, in order of their ANSI codes.
+            pub const ALL: [Self; #count] = [#(Self::#variants),*];
+
+            /// Get the ANSI color's code.
+            ///
+            /// Example:
+            ///
+            /// ```rust
+            /// use catppuccin::AnsiColorName;
+            ///
+            /// assert_eq!(AnsiColorName::BrightRed.code(), 9);
+            /// assert_eq!(AnsiColorName::try_from(9), Ok(AnsiColorName::BrightRed));
+            /// assert!(AnsiColorName::try_from(16).is_err());
+            /// ```
+            #[must_use]
+            pub const fn code(&self) -> u8 {
+                match self {
+                    #(Self::#variants => #codes),*
+                }
+            }
+        }
+
+        impl TryFrom<u8> for AnsiColorName {
+            type Error = TryFromAnsiCodeError;
+
+            fn try_from(code: u8) -> Result<Self, Self::Error> {
+                match code {
+                    #(#codes => Ok(Self::#variants),)*
+                    _ => Err(TryFromAnsiCodeError(code)),
+                }
+            }
+        }
+    }
+}
+
+fn make_ansi_color_pair_name_all_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
+    let variants = ansi_color_pairs_in_order(sample_flavor)
+        .map(|(identifier, ..)| format_ident!("{}", titlecase(identifier)))
+        .collect::<Vec<_>>();
+    let count = variants.len();
+    quote! {
+        impl AnsiColorPairName {
+            /// All ANSI color pair names, in order.
+            pub const ALL: [Self; #count] = [#(Self::#variants),*];
+        }
+    }
+}
+
+fn make_accent_name_enum_tokens(sample_flavor: &Flavor) -> TokenStream {
+    let variants = accents_in_order(sample_flavor).map(|(name, _)| {
+        let ident = format_ident!("{}", titlecase(name));
+        let circles = format!(" {}", color_palette_circles(name));
+        quote! {
+            #[doc = #circles]
+            #ident
+        }
+    });
+    quote! {
+        /// Enum of the Catppuccin accent colors; the subset of [`ColorName`] for which
+        /// [`ColorName::is_accent`] is true. Can be used to index into a [`FlavorColors`].
+        ///
+        /// Ordered by each color's [`order`](ColorName::order) in the palette.
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum AccentName {
+            #(#variants),*
+        }
+    }
+}
+
+fn make_accent_name_impl_tokens(sample_flavor: &Flavor) -> TokenStream {
+    let variants = accents_in_order(sample_flavor)
+        .map(|(identifier, _)| format_ident!("{}", titlecase(identifier)))
+        .collect::<Vec<_>>();
+    let fields = accents_in_order(sample_flavor)
+        .map(|(identifier, _)| format_ident!("{identifier}"))
+        .collect::<Vec<_>>();
+    let identifiers = accents_in_order(sample_flavor)
+        .map(|(identifier, _)| identifier)
+        .collect::<Vec<_>>();
+    let count = variants.len();
+    quote! {
+        impl AccentName {
+            /// All accent names, in order.
+            pub const ALL: [Self; #count] = [#(Self::#variants),*];
+
+            /// Get the accent's identifier; the lowercase key used to identify the color.
+            /// This is the same as the identifier of the corresponding [`ColorName`].
+            #[must_use]
+            pub const fn identifier(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #identifiers),*
+                }
+            }
+        }
+
+        impl From<AccentName> for ColorName {
+            fn from(value: AccentName) -> Self {
+                match value {
+                    #(AccentName::#variants => Self::#variants),*
+                }
+            }
+        }
+
+        impl TryFrom<ColorName> for AccentName {
+            type Error = TryFromColorNameError;
+
+            fn try_from(value: ColorName) -> Result<Self, Self::Error> {
+                match value {
+                    #(ColorName::#variants => Ok(Self::#variants),)*
+                    _ => Err(TryFromColorNameError(value)),
+                }
+            }
+        }
+
+        impl core::fmt::Display for AccentName {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                ColorName::from(*self).fmt(f)
+            }
+        }
+
+        impl AccentName {
+            /// Parse a name like [`FromStr`](core::str::FromStr), but with a
+            /// suggestion of a similar name in the error if parsing fails.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the input is not a name of this kind.
+            pub fn parse_with_suggestion(s: &str) -> Result<Self, UnknownColorNameError> {
+                crate::parse::parse_name(
+                    s,
+                    &[#((#identifiers, Self::#variants)),*],
+                    crate::parse::ACCENT_NAME_ALIASES,
+                )
+                .map_err(|suggestion| UnknownColorNameError { suggestion })
+            }
+        }
+
+        impl core::str::FromStr for AccentName {
             type Err = ParseColorNameError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    #(#match_arms),*,
-                    _ => Err(ParseColorNameError),
+                Self::parse_with_suggestion(s).map_err(ParseColorNameError::from)
+            }
+        }
+
+        impl Index<AccentName> for FlavorColors {
+            type Output = Color;
+
+            fn index(&self, index: AccentName) -> &Self::Output {
+                self.get_accent(index)
+            }
+        }
+
+        impl FlavorColors {
+            /// Get an accent color by name.
+            ///
+            /// This is equivalent to using the index operator, but can also be used in
+            /// const contexts.
+            #[must_use]
+            pub const fn get_accent(&self, name: AccentName) -> &Color {
+                match name {
+                    #(AccentName::#variants => &self.#fields),*
                 }
             }
         }
@@ -757,7 +1071,22 @@ fn make_ansi_color_pair_name_fromstr_impl_tokens(sample_flavor: &Flavor) -> Toke
 fn make_palette_const_tokens(palette: &Palette) -> TokenStream {
     let flavors =
         flavors_in_order(palette).map(|(identifier, flavor)| make_flavor_entry(identifier, flavor));
+    let [major, minor, patch]: [u32; 3] = palette
+        .version
+        .split('.')
+        .map(str::parse)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()
+        .and_then(|parts| parts.try_into().ok())
+        .expect("palette version is major.minor.patch");
     let tokens = quote! {
+        /// The version of the Catppuccin palette spec that [`PALETTE`] follows.
+        pub const PALETTE_VERSION: PaletteVersion = PaletteVersion {
+            major: #major,
+            minor: #minor,
+            patch: #patch,
+        };
+
         /// The Catppuccin palette. This constant will generally be your entrypoint
         /// into using the crate.
         #[allow(clippy::unreadable_literal)]
@@ -769,6 +1098,91 @@ fn make_palette_const_tokens(palette: &Palette) -> TokenStream {
     tokens
 }
 
+fn make_color_macro_tokens(palette: &Palette) -> TokenStream {
+    let flavor_identifiers = flavors_in_order(palette)
+        .map(|(identifier, _)| identifier.as_str())
+        .collect::<Vec<_>>();
+    let (_, sample_flavor) = flavors_in_order(palette)
+        .next()
+        .expect("at least one flavor");
+    let color_identifiers = colors_in_order(sample_flavor)
+        .map(|(identifier, _)| identifier.as_str())
+        .collect::<Vec<_>>();
+    let ansi_color_identifiers = ansi_colors_in_order(sample_flavor)
+        .map(|(identifier, _)| identifier)
+        .collect::<Vec<_>>();
+
+    let color_arms = flavor_identifiers.iter().flat_map(|flavor| {
+        let flavor_ident = format_ident!("{flavor}");
+        color_identifiers.iter().map(move |color| {
+            let path = format!("{flavor}.{color}");
+            let color_ident = format_ident!("{color}");
+            quote! {
+                (#path) => {{
+                    const COLOR: &$crate::Color = &$crate::PALETTE.#flavor_ident.colors.#color_ident;
+                    COLOR
+                }};
+            }
+        })
+    });
+    let ansi_color_arms = flavor_identifiers.iter().flat_map(|flavor| {
+        let flavor_ident = format_ident!("{flavor}");
+        ansi_color_identifiers.iter().map(move |color| {
+            let path = format!("{flavor}.ansi.{color}");
+            let color_ident = format_ident!("{color}");
+            quote! {
+                (#path) => {{
+                    const COLOR: &$crate::AnsiColor =
+                        &$crate::PALETTE.#flavor_ident.ansi_colors.#color_ident;
+                    COLOR
+                }};
+            }
+        })
+    });
+    let error_suffix = format!(
+        "`; expected `<flavor>.<color>` or `<flavor>.ansi.<ansi color>`\n\
+         valid flavors: {}\n\
+         valid colors: {}\n\
+         valid ansi colors: {}",
+        flavor_identifiers.join(", "),
+        color_identifiers.join(", "),
+        ansi_color_identifiers.join(", "),
+    );
+
+    quote! {
+        /// Look up a color by its path at compile time.
+        ///
+        /// Paths take the form `"<flavor>.<color>"`, which resolves to a
+        /// `&'static` [`Color`], or `"<flavor>.ansi.<ansi color>"`, which resolves to
+        /// a `&'static` [`AnsiColor`]. Each segment is an identifier, as returned by
+        /// [`FlavorName::identifier`], [`ColorName::identifier`] and
+        /// [`AnsiColorName::identifier`]. Unknown paths are a compile error listing
+        /// the valid identifiers.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// const ACCENT: &catppuccin::Color = catppuccin::color!("mocha.mauve");
+        /// assert_eq!(ACCENT, &catppuccin::PALETTE.mocha.colors.mauve);
+        ///
+        /// let error = catppuccin::color!("latte.ansi.bright_red");
+        /// assert_eq!(error.code, 9);
+        /// ```
+        ///
+        /// ```compile_fail
+        /// let typo = catppuccin::color!("mocha.muave");
+        /// ```
+        #[macro_export]
+        macro_rules! color {
+            #(#color_arms)*
+            #(#ansi_color_arms)*
+            ($path:literal) => {
+                compile_error!(concat!("unknown color path `", $path, #error_suffix))
+            };
+        }
+    }
+}
+
 fn make_flavor_entry(identifier: &str, flavor: &Flavor) -> TokenStream {
     let Flavor {
         emoji, order, dark, ..
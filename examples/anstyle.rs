@@ -0,0 +1,31 @@
+//! Example demonstrating integration with the `anstyle` crate, by theming `clap`'s help output.
+use clap::{builder::Styles, Arg, Command};
+
+fn main() {
+    let styles = catppuccin::PALETTE.mocha.cli_styles();
+
+    let mut command = Command::new("catppuccin")
+        .about("🦀 Soothing pastel theme for Rust.")
+        .styles(
+            Styles::styled()
+                .header(styles.header)
+                .usage(styles.usage)
+                .literal(styles.literal)
+                .placeholder(styles.placeholder)
+                .error(styles.error)
+                .valid(styles.valid)
+                .invalid(styles.invalid),
+        )
+        .arg(
+            Arg::new("flavor")
+                .long("flavor")
+                .value_name("FLAVOR")
+                .help("The flavor to use"),
+        );
+
+    command.print_help().expect("help can be printed");
+
+    let teal: anstyle::Style =
+        anstyle::Style::new().fg_color(Some(catppuccin::PALETTE.mocha.colors.teal.into()));
+    println!("\n{teal}Teal text{teal:#}");
+}
@@ -0,0 +1,48 @@
+//! Example demonstrating integration with the `crossterm` crate.
+use std::io::{stdout, Write};
+
+use catppuccin::{Role, Theme};
+
+use crossterm::{
+    queue,
+    style::{Print, PrintStyledContent, ResetColor},
+};
+
+fn main() -> std::io::Result<()> {
+    let mut stdout = stdout();
+
+    for flavor in &catppuccin::PALETTE {
+        queue!(
+            stdout,
+            flavor.crossterm_set_colors(),
+            Print(format!(" {} {} ", flavor.emoji, flavor.name)),
+            ResetColor,
+            Print("\n"),
+        )?;
+
+        let theme = Theme::new(*flavor);
+        for role in [Role::Success, Role::Warning, Role::Error] {
+            let label = format!(" {} ", role.identifier());
+            queue!(
+                stdout,
+                PrintStyledContent(theme.crossterm_styled(role, label))
+            )?;
+        }
+        queue!(stdout, Print("\n"))?;
+
+        for color in flavor {
+            queue!(stdout, PrintStyledContent(color.crossterm_styled("██")))?;
+        }
+        queue!(stdout, Print("\n"))?;
+
+        for ansi_color in &flavor.ansi_colors {
+            queue!(
+                stdout,
+                PrintStyledContent(ansi_color.crossterm_styled("██"))
+            )?;
+        }
+        queue!(stdout, Print("\n\n"))?;
+    }
+
+    stdout.flush()
+}
@@ -0,0 +1,16 @@
+//! Example demonstrating integration with the `image` crate, by rendering swatch
+//! images of each flavor and of the whole palette.
+use catppuccin::PALETTE;
+
+fn main() -> Result<(), image::ImageError> {
+    for flavor in &PALETTE {
+        let path = format!("swatches-{}.png", flavor.identifier());
+        flavor.swatch_image().save(&path)?;
+        println!("Wrote {path}");
+    }
+
+    PALETTE.swatch_image().save("swatches.png")?;
+    println!("Wrote swatches.png");
+
+    Ok(())
+}
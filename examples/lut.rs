@@ -0,0 +1,25 @@
+//! Example demonstrating how to export 3D lookup tables for each flavor, as `.cube`
+//! files and Hald CLUT images.
+use catppuccin::{LutMapping, Recolor, PALETTE};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for flavor in &PALETTE {
+        let recolor = Recolor::new(flavor);
+
+        let path = format!("catppuccin-{}.cube", flavor.identifier());
+        let lut = recolor.lut(33, LutMapping::Smooth);
+        std::fs::write(&path, lut.to_cube(&format!("Catppuccin {}", flavor.name)))?;
+        println!("Wrote {path}");
+
+        let path = format!("catppuccin-{}-hald.png", flavor.identifier());
+        let clut = recolor.hald_clut(8, LutMapping::Nearest);
+        #[allow(clippy::cast_possible_truncation)]
+        let width = clut.width() as u32;
+        image::RgbImage::from_raw(width, width, clut.pixels)
+            .expect("Hald CLUT has the right number of pixels")
+            .save(&path)?;
+        println!("Wrote {path}");
+    }
+
+    Ok(())
+}
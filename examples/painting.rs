@@ -0,0 +1,42 @@
+//! Example demonstrating integration with the `colored`, `nu-ansi-term`, `owo-colors`
+//! and `yansi` crates.
+use catppuccin::Flavor;
+
+fn colored(flavor: &Flavor) {
+    use colored::Colorize;
+
+    println!("{}", "Painted with colored".color(flavor.colors.mauve));
+}
+
+fn nu_ansi_term(flavor: &Flavor) {
+    println!(
+        "{}",
+        nu_ansi_term::Color::from(flavor.colors.peach).paint("Painted with nu-ansi-term")
+    );
+}
+
+fn owo_colors(flavor: &Flavor) {
+    println!(
+        "{}",
+        flavor.colors.green.owo_color(&"Painted with owo-colors")
+    );
+    for ansi_color in &flavor.ansi_colors {
+        print!("{}", ansi_color.owo_color(&"██"));
+    }
+    println!();
+}
+
+fn yansi(flavor: &Flavor) {
+    use yansi::Paint;
+
+    println!("{}", "Painted with yansi".paint(flavor.colors.sapphire));
+}
+
+fn main() {
+    let mocha = catppuccin::PALETTE.mocha;
+
+    colored(&mocha);
+    nu_ansi_term(&mocha);
+    owo_colors(&mocha);
+    yansi(&mocha);
+}
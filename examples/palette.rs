@@ -0,0 +1,20 @@
+//! Example demonstrating integration with the `palette` crate.
+use palette::{Darken, LinSrgb, Mix, Oklch, Srgb};
+
+fn main() {
+    let mocha = catppuccin::PALETTE.mocha;
+
+    let mauve: Oklch = mocha.colors.mauve.into();
+    let peach: Oklch = mocha.colors.peach.into();
+    println!("Mauve in Oklch: {mauve:?}");
+    println!("Peach in Oklch: {peach:?}");
+
+    let mauve: LinSrgb = mocha.colors.mauve.into();
+    let halfway: Srgb<u8> = Srgb::from_linear(mauve.mix(mocha.colors.peach.into(), 0.5));
+    println!("Halfway between mauve and peach: #{halfway:x}");
+
+    let darker: Srgb<u8> = Srgb::<f32>::from(mocha.colors.base)
+        .darken(0.5)
+        .into_format();
+    println!("Mocha's base, 50% darker: #{darker:x}");
+}
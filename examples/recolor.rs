@@ -0,0 +1,53 @@
+//! Example demonstrating how to recolor an image to a flavor, with and without dithering.
+use catppuccin::{ColorSelection, Dither, Recolor, PALETTE};
+use image::RgbImage;
+
+fn main() -> Result<(), image::ImageError> {
+    // a smooth gradient across hue and lightness, standing in for a wallpaper
+    let gradient = RgbImage::from_fn(512, 256, |x, y| {
+        #[allow(clippy::cast_possible_truncation)]
+        let channel = |v: u32| (v * 255 / 511) as u8;
+        image::Rgb([channel(x), channel(y * 2), channel(511 - x)])
+    });
+
+    let mocha = Recolor::new(&PALETTE.mocha);
+    let variants = [
+        (
+            "none",
+            Recolor {
+                dither: Dither::None,
+                ..mocha
+            },
+        ),
+        ("floyd-steinberg", mocha),
+        (
+            "ordered",
+            Recolor {
+                dither: Dither::Ordered,
+                ..mocha
+            },
+        ),
+        (
+            "accents",
+            Recolor {
+                colors: ColorSelection::Accents,
+                ..mocha
+            },
+        ),
+    ];
+
+    for (name, recolor) in variants {
+        let mut image = gradient.clone();
+        recolor.quantize(&mut image, 512);
+        let path = format!("recolor-{name}.png");
+        image.save(&path)?;
+        println!("Wrote {path}");
+    }
+
+    let mut image = gradient;
+    mocha.tint(&mut image, 0.6);
+    image.save("recolor-tint.png")?;
+    println!("Wrote recolor-tint.png");
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+//! Example demonstrating how to export the palette as a stylesheet of CSS custom
+//! properties, as SCSS, Less and Stylus variables, and as Tailwind CSS themes.
+use catppuccin::{
+    Css, FlavorName, Preprocessor, PreprocessorVariables, Tailwind, TailwindFormat, PALETTE,
+};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = "catppuccin.css";
+    std::fs::write(path, Css::new(&PALETTE).to_string())?;
+    println!("Wrote {path}");
+
+    for (preprocessor, path) in [
+        (Preprocessor::Scss, "_catppuccin.scss"),
+        (Preprocessor::Less, "catppuccin.less"),
+        (Preprocessor::Stylus, "catppuccin.styl"),
+    ] {
+        let variables = PreprocessorVariables::new(&PALETTE, preprocessor);
+        std::fs::write(path, variables.to_string())?;
+        println!("Wrote {path}");
+    }
+
+    let path = "catppuccin.preset.js";
+    let preset = Tailwind::new(&PALETTE, TailwindFormat::V3Preset);
+    std::fs::write(path, preset.to_string())?;
+    println!("Wrote {path}");
+
+    // semantic colors such as `bg-base`, switchable at runtime alongside catppuccin.css
+    let path = "catppuccin.theme.css";
+    let theme = Tailwind {
+        semantic: Some(FlavorName::Mocha),
+        ..Tailwind::new(&PALETTE, TailwindFormat::V4Theme)
+    };
+    std::fs::write(path, theme.to_string())?;
+    println!("Wrote {path}");
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+//! Example demonstrating how to export each flavor as a palette file for GIMP,
+//! Inkscape, Krita, Paint.NET and Procreate, and the whole palette as an Adobe
+//! Swatch Exchange file.
+use catppuccin::{Ase, SwatchFormat, Swatches, PALETTE};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for flavor in &PALETTE {
+        for format in [
+            SwatchFormat::Gpl,
+            SwatchFormat::Kpl,
+            SwatchFormat::PaintDotNet,
+            SwatchFormat::Procreate,
+        ] {
+            let path = format!("catppuccin-{}.{}", flavor.identifier(), format.extension());
+            std::fs::write(&path, Swatches::new(flavor, format).to_bytes())?;
+            println!("Wrote {path}");
+        }
+    }
+
+    std::fs::write("catppuccin.ase", Ase::from(&PALETTE).to_bytes())?;
+    println!("Wrote catppuccin.ase");
+    Ok(())
+}
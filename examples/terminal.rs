@@ -0,0 +1,25 @@
+//! Example demonstrating how to export each flavor as a theme for every
+//! supported terminal emulator.
+use catppuccin::{TerminalFormat, TerminalTheme, PALETTE};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for (directory, format) in [
+        ("alacritty", TerminalFormat::Alacritty),
+        ("kitty", TerminalFormat::Kitty),
+        ("wezterm", TerminalFormat::WezTerm),
+        ("foot", TerminalFormat::Foot),
+        ("ghostty", TerminalFormat::Ghostty),
+    ] {
+        std::fs::create_dir_all(directory)?;
+        for flavor in &PALETTE {
+            let path = format!(
+                "{directory}/catppuccin-{}.{}",
+                flavor.identifier(),
+                format.extension()
+            );
+            std::fs::write(&path, TerminalTheme::new(flavor, format).to_string())?;
+            println!("Wrote {path}");
+        }
+    }
+    Ok(())
+}
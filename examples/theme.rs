@@ -0,0 +1,34 @@
+//! Example demonstrating how to load a theme file, and how to write the JSON
+//! Schema of the theme file format for editor validation.
+use catppuccin::{Role, Theme, ThemeSchema};
+
+const THEME: &str = r##"
+flavor = "mocha"
+
+[colors]
+base = "#11111b"
+crust = "rgb(0, 0, 0)"
+
+[roles]
+link = "sapphire"
+cursor = "mauve"
+"##;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // load the theme file given as the first argument, or the one above
+    let theme = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => THEME.to_owned(),
+    };
+    let theme: Theme = toml::from_str(&theme)?;
+    for role in Role::ALL {
+        let color = theme[role];
+        println!("{:>16}: {} ({})", role.identifier(), color.hex, color.name);
+    }
+
+    let path = "catppuccin-theme.schema.json";
+    std::fs::write(path, ThemeSchema.to_string())?;
+    println!("Wrote {path}");
+
+    Ok(())
+}
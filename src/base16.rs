@@ -0,0 +1,440 @@
+//! Export and import of flavors as base16 and base24 schemes.
+use core::fmt;
+
+use crate::{ColorName, Flavor};
+
+/// The colors of a base24 scheme, in order of their slots `base00` to `base17`,
+/// as mapped by the official Catppuccin schemes.
+///
+/// The first 16 slots make up a base16 scheme.
+const SLOTS: [ColorName; 24] = [
+    ColorName::Base,
+    ColorName::Mantle,
+    ColorName::Surface0,
+    ColorName::Surface1,
+    ColorName::Surface2,
+    ColorName::Text,
+    ColorName::Rosewater,
+    ColorName::Lavender,
+    ColorName::Red,
+    ColorName::Peach,
+    ColorName::Yellow,
+    ColorName::Green,
+    ColorName::Teal,
+    ColorName::Blue,
+    ColorName::Mauve,
+    ColorName::Flamingo,
+    ColorName::Mantle,
+    ColorName::Crust,
+    ColorName::Maroon,
+    ColorName::Rosewater,
+    ColorName::Green,
+    ColorName::Sky,
+    ColorName::Sapphire,
+    ColorName::Pink,
+];
+
+/// The scheme systems supported by [`Base16Scheme`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base16System {
+    /// A [base16](https://github.com/tinted-theming/home) scheme of 16 colors.
+    Base16,
+    /// A base24 scheme, which extends base16 with 8 more colors.
+    Base24,
+}
+
+impl Base16System {
+    /// Get the identifier of the system, as used in the `system` field of a scheme.
+    #[must_use]
+    pub const fn identifier(&self) -> &'static str {
+        match self {
+            Self::Base16 => "base16",
+            Self::Base24 => "base24",
+        }
+    }
+
+    /// Get the color in each slot of the system, from `base00` onwards.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{Base16System, ColorName};
+    ///
+    /// let slots = Base16System::Base24.colors();
+    /// assert_eq!(slots.len(), 24);
+    /// assert_eq!(slots[0x05], ColorName::Text);
+    /// assert_eq!(slots[0x08], ColorName::Red);
+    /// assert_eq!(slots[0x11], ColorName::Crust);
+    /// ```
+    #[must_use]
+    pub const fn colors(&self) -> &'static [ColorName] {
+        match self {
+            Self::Base16 => SLOTS.split_at(16).0,
+            Self::Base24 => &SLOTS,
+        }
+    }
+}
+
+/// The output formats supported by [`Base16Scheme`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base16Format {
+    /// YAML, as used by the [tinted-theming](https://github.com/tinted-theming/schemes) schemes.
+    Yaml,
+    /// JSON, with the same structure as [`Base16Format::Yaml`].
+    Json,
+}
+
+/// Exports a flavor as a base16 or base24 scheme.
+///
+/// Schemes follow the tinted-theming format, with `system`, `name`, `author`
+/// and `variant` fields and the colors in a `palette` map. They can be imported
+/// again with [`Flavor::from_base16`].
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{Base16Format, Base16Scheme, Base16System, PALETTE};
+///
+/// let yaml = Base16Scheme::new(&PALETTE.mocha, Base16System::Base16, Base16Format::Yaml);
+/// assert_eq!(
+///     yaml.to_string().lines().take(7).collect::<Vec<_>>(),
+///     [
+///         r#"system: "base16""#,
+///         r#"name: "Catppuccin Mocha""#,
+///         r#"author: "https://github.com/catppuccin/catppuccin""#,
+///         r#"variant: "dark""#,
+///         "palette:",
+///         r##"  base00: "#1e1e2e" # base"##,
+///         r##"  base01: "#181825" # mantle"##,
+///     ],
+/// );
+///
+/// let json = Base16Scheme::new(&PALETTE.latte, Base16System::Base24, Base16Format::Json);
+/// assert!(json.to_string().contains(r##""base17": "#ea76cb""##));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Base16Scheme<'a> {
+    /// The flavor to export.
+    pub flavor: &'a Flavor,
+    /// The scheme system.
+    pub system: Base16System,
+    /// The output format.
+    pub format: Base16Format,
+}
+
+impl<'a> Base16Scheme<'a> {
+    /// Create a scheme exporter for the given flavor, system and format.
+    #[must_use]
+    pub const fn new(flavor: &'a Flavor, system: Base16System, format: Base16Format) -> Self {
+        Self {
+            flavor,
+            system,
+            format,
+        }
+    }
+}
+
+impl fmt::Display for Base16Scheme<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fields = [
+            ("system", self.system.identifier()),
+            ("author", "https://github.com/catppuccin/catppuccin"),
+            ("variant", if self.flavor.dark { "dark" } else { "light" }),
+        ];
+        let slots = self.system.colors().iter().enumerate();
+        match self.format {
+            Base16Format::Yaml => {
+                writeln!(f, "system: \"{}\"", fields[0].1)?;
+                writeln!(f, "name: \"Catppuccin {}\"", self.flavor.name)?;
+                for (key, value) in &fields[1..] {
+                    writeln!(f, "{key}: \"{value}\"")?;
+                }
+                writeln!(f, "palette:")?;
+                for (slot, name) in slots {
+                    let color = &self.flavor[*name];
+                    writeln!(
+                        f,
+                        "  base{slot:02X}: \"{}\" # {}",
+                        color.hex,
+                        color.identifier()
+                    )?;
+                }
+            }
+            Base16Format::Json => {
+                writeln!(f, "{{\n  \"system\": \"{}\",", fields[0].1)?;
+                writeln!(f, "  \"name\": \"Catppuccin {}\",", self.flavor.name)?;
+                for (key, value) in &fields[1..] {
+                    writeln!(f, "  \"{key}\": \"{value}\",")?;
+                }
+                writeln!(f, "  \"palette\": {{")?;
+                let last = self.system.colors().len() - 1;
+                for (slot, name) in slots {
+                    let comma = if slot == last { "" } else { "," };
+                    let hex = self.flavor[*name].hex;
+                    writeln!(f, "    \"base{slot:02X}\": \"{hex}\"{comma}")?;
+                }
+                writeln!(f, "  }}\n}}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+mod _serde {
+    use core::fmt;
+
+    use serde::{
+        de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+        Deserialize, Deserializer,
+    };
+
+    use super::SLOTS;
+    use crate::{Flavor, Hex, Hsl};
+
+    impl Flavor {
+        /// Import a flavor from a base16 or base24 scheme, so that other themes
+        /// can be compared against Catppuccin.
+        ///
+        /// Both the tinted-theming format, with the colors in a `palette` map, and
+        /// the legacy format, with the colors at the top level, are supported;
+        /// colors may be written with or without a leading `#`.
+        ///
+        /// Each slot replaces the color of `base` it is mapped to by
+        /// [`Base16System::colors`](crate::Base16System::colors); colors that no
+        /// slot maps to keep their values from `base`. The base24 slots `base10`,
+        /// `base13` and `base14` map to colors that base16 slots already cover,
+        /// and are ignored. The `variant` field, if present, sets
+        /// [`Flavor::dark`]. Other metadata is ignored.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// use catppuccin::{Base16Format, Base16Scheme, Base16System, Flavor, PALETTE};
+        ///
+        /// let scheme = r#"
+        /// scheme: "Tomorrow Night"
+        /// author: "Chris Kempson (http://chriskempson.com)"
+        /// base00: "1d1f21"
+        /// base05: "c5c8c6"
+        /// base08: "cc6666"
+        /// "#;
+        /// let tomorrow = Flavor::from_base16(serde_yaml::Deserializer::from_str(scheme), &PALETTE.mocha)?;
+        /// assert_eq!(tomorrow.colors.base.hex.to_string(), "#1d1f21");
+        /// assert_eq!(tomorrow.colors.red.hex.to_string(), "#cc6666");
+        /// assert_eq!(tomorrow.colors.mauve, PALETTE.mocha.colors.mauve);
+        ///
+        /// // the colors in exported schemes round-trip
+        /// let system = Base16System::Base24;
+        /// for flavor in &PALETTE {
+        ///     let scheme = Base16Scheme::new(flavor, system, Base16Format::Yaml).to_string();
+        ///     let deserializer = serde_yaml::Deserializer::from_str(&scheme);
+        ///     let imported = Flavor::from_base16(deserializer, &PALETTE.mocha)?;
+        ///     assert!(system.colors().iter().all(|&name| imported[name].rgb == flavor[name].rgb));
+        /// }
+        ///
+        /// let scheme = Base16Scheme::new(&PALETTE.latte, Base16System::Base16, Base16Format::Json).to_string();
+        /// let deserializer = &mut serde_json::Deserializer::from_str(&scheme);
+        /// let latte = Flavor::from_base16(deserializer, &PALETTE.mocha).unwrap();
+        /// assert!(!latte.dark);
+        /// assert_eq!(latte.colors.blue.hex, PALETTE.latte.colors.blue.hex);
+        /// assert_eq!(latte.colors.sky, PALETTE.mocha.colors.sky);
+        ///
+        /// // colors must have exactly six hex digits
+        /// let scheme = "palette:\n  base00: \"fff\"\n";
+        /// assert!(Flavor::from_base16(serde_yaml::Deserializer::from_str(scheme), &PALETTE.mocha).is_err());
+        /// # Ok::<(), serde_yaml::Error>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the scheme is malformed, contains no colors, has
+        /// a color that is not a six-digit hex color, or has an unknown slot in
+        /// its `palette` map.
+        pub fn from_base16<'de, D>(deserializer: D, base: &Self) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(SchemeVisitor {
+                flavor: *base,
+                found: false,
+            })
+        }
+    }
+
+    /// The slot index of a key such as `base0A`.
+    fn parse_slot(key: &str) -> Option<usize> {
+        let digits = key.strip_prefix("base")?;
+        let slot = usize::from_str_radix(digits, 16).ok()?;
+        (digits.len() == 2 && slot < SLOTS.len()).then_some(slot)
+    }
+
+    /// A key at the top level of a scheme.
+    enum SchemeKey {
+        Palette,
+        Variant,
+        Slot(usize),
+        Other,
+    }
+
+    impl<'de> Deserialize<'de> for SchemeKey {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(SchemeKeyVisitor)
+        }
+    }
+
+    struct SchemeKeyVisitor;
+
+    impl Visitor<'_> for SchemeKeyVisitor {
+        type Value = SchemeKey;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a scheme field")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(match v {
+                "palette" => SchemeKey::Palette,
+                "variant" => SchemeKey::Variant,
+                _ => parse_slot(v).map_or(SchemeKey::Other, SchemeKey::Slot),
+            })
+        }
+    }
+
+    /// A key in the `palette` map of a scheme.
+    struct Slot(usize);
+
+    impl<'de> Deserialize<'de> for Slot {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(SlotVisitor)
+        }
+    }
+
+    struct SlotVisitor;
+
+    impl Visitor<'_> for SlotVisitor {
+        type Value = Slot;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a slot from `base00` to `base17`")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            parse_slot(v).map(Slot).ok_or_else(|| {
+                E::custom(format_args!(
+                    "unknown slot `{v}`, expected `base00` to `base17`"
+                ))
+            })
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Variant {
+        Dark,
+        Light,
+    }
+
+    /// Deserializes a scheme into a flavor.
+    struct SchemeVisitor {
+        flavor: Flavor,
+        found: bool,
+    }
+
+    impl SchemeVisitor {
+        /// Set the color in the given slot.
+        ///
+        /// Colors whose value is unchanged keep their HSL channels from the base
+        /// flavor, rather than recomputing them from RGB.
+        fn set(&mut self, slot: usize, hex: Hex) {
+            self.found = true;
+            let name = SLOTS[slot];
+            if SLOTS.iter().position(|n| *n == name) != Some(slot) {
+                return;
+            }
+            let color = &mut self.flavor.colors[name];
+            if color.hex != hex {
+                (color.hex, color.rgb, color.hsl) = (hex, hex.0, Hsl::from(hex.0));
+            }
+        }
+    }
+
+    impl<'de> Visitor<'de> for SchemeVisitor {
+        type Value = Flavor;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a base16 or base24 scheme")
+        }
+
+        fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            while let Some(key) = map.next_key()? {
+                match key {
+                    SchemeKey::Palette => map.next_value_seed(PaletteSeed(&mut self))?,
+                    SchemeKey::Variant => {
+                        self.flavor.dark = matches!(map.next_value()?, Variant::Dark);
+                    }
+                    SchemeKey::Slot(slot) => {
+                        let hex = map.next_value()?;
+                        self.set(slot, hex);
+                    }
+                    SchemeKey::Other => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+            if self.found {
+                Ok(self.flavor)
+            } else {
+                Err(de::Error::custom("scheme contains no colors"))
+            }
+        }
+    }
+
+    /// Deserializes the `palette` map of a scheme.
+    struct PaletteSeed<'a>(&'a mut SchemeVisitor);
+
+    impl<'de> DeserializeSeed<'de> for PaletteSeed<'_> {
+        type Value = ();
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for PaletteSeed<'_> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map of slots to colors")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            while let Some(Slot(slot)) = map.next_key()? {
+                let hex = map.next_value()?;
+                self.0.set(slot, hex);
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Conversions to [`image`] pixel types, and rendering of flavors to swatch images.
+use alloc::vec::Vec;
+
+use image::{Rgb, RgbImage, Rgba};
+
+use crate::{AnsiColor, Color, Flavor, Palette};
+
+/// Number of swatches per row.
+const COLUMNS: u32 = 8;
+/// Size multiplier for the built-in bitmap font.
+const FONT_SCALE: u32 = 2;
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 9;
+const GLYPH_ADVANCE: u32 = (GLYPH_WIDTH + 1) * FONT_SCALE;
+const PADDING: u32 = 16;
+const SWATCH_WIDTH: u32 = 176;
+const SWATCH_HEIGHT: u32 = 64;
+const LABEL_HEIGHT: u32 = GLYPH_HEIGHT * FONT_SCALE + 6;
+const CELL_HEIGHT: u32 = SWATCH_HEIGHT + LABEL_HEIGHT;
+const IMAGE_WIDTH: u32 = PADDING + COLUMNS * (SWATCH_WIDTH + PADDING);
+
+impl From<Color> for Rgb<u8> {
+    fn from(value: Color) -> Self {
+        Self([value.rgb.r, value.rgb.g, value.rgb.b])
+    }
+}
+
+impl From<AnsiColor> for Rgb<u8> {
+    fn from(value: AnsiColor) -> Self {
+        Self([value.rgb.r, value.rgb.g, value.rgb.b])
+    }
+}
+
+impl From<Color> for Rgba<u8> {
+    fn from(value: Color) -> Self {
+        Self([value.rgb.r, value.rgb.g, value.rgb.b, 255])
+    }
+}
+
+impl From<AnsiColor> for Rgba<u8> {
+    fn from(value: AnsiColor) -> Self {
+        Self([value.rgb.r, value.rgb.g, value.rgb.b, 255])
+    }
+}
+
+impl Flavor {
+    /// Render the flavor to a swatch grid image.
+    ///
+    /// The image contains the flavor's colors, followed by its ANSI colors, followed
+    /// by its ANSI color pairs, each labeled by identifier. The flavor's `base` and
+    /// `text` colors are used for the background and labels.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// let image = catppuccin::PALETTE.mocha.swatch_image();
+    /// assert_eq!(image.get_pixel(0, 0), &catppuccin::PALETTE.mocha.colors.base.into());
+    /// ```
+    #[must_use]
+    pub fn swatch_image(&self) -> RgbImage {
+        let mut image = RgbImage::new(IMAGE_WIDTH, flavor_height());
+        draw_flavor(&mut image, self, 0);
+        image
+    }
+}
+
+impl Palette {
+    /// Render every flavor in the palette to a single swatch grid image, with the
+    /// flavors stacked vertically in order.
+    ///
+    /// See [`Flavor::swatch_image`] for the layout of each flavor.
+    #[must_use]
+    pub fn swatch_image(&self) -> RgbImage {
+        let flavors = self.all_flavors();
+        #[allow(clippy::cast_possible_truncation)]
+        let mut image = RgbImage::new(IMAGE_WIDTH, flavor_height() * flavors.len() as u32);
+        for (flavor, y) in flavors
+            .into_iter()
+            .zip((0..).step_by(flavor_height() as usize))
+        {
+            draw_flavor(&mut image, flavor, y);
+        }
+        image
+    }
+}
+
+const fn rows(cells: u32) -> u32 {
+    cells.div_ceil(COLUMNS)
+}
+
+const fn section_height(cells: u32) -> u32 {
+    rows(cells) * (CELL_HEIGHT + PADDING)
+}
+
+const fn flavor_height() -> u32 {
+    PADDING + LABEL_HEIGHT + PADDING + section_height(26) + section_height(16) + section_height(8)
+}
+
+fn draw_flavor(image: &mut RgbImage, flavor: &Flavor, y: u32) {
+    let background = flavor.colors.base.into();
+    let text = flavor.colors.text.into();
+    fill_rect(image, 0, y, IMAGE_WIDTH, flavor_height(), background);
+    draw_text(image, PADDING, y + PADDING, flavor.identifier(), text);
+
+    let mut y = y + PADDING + LABEL_HEIGHT + PADDING;
+    let colors = flavor
+        .colors
+        .iter()
+        .map(|color| ([Rgb::from(*color)].to_vec(), color.identifier()));
+    y = draw_section(image, y, colors, text);
+
+    let ansi_colors = flavor
+        .ansi_colors
+        .iter()
+        .map(|color| ([Rgb::from(*color)].to_vec(), color.name.identifier()));
+    y = draw_section(image, y, ansi_colors, text);
+
+    let pairs = flavor.ansi_colors.all_pairs();
+    let ansi_color_pairs = pairs.iter().map(|pair| {
+        (
+            [pair.normal.into(), pair.bright.into()].to_vec(),
+            pair.name.identifier(),
+        )
+    });
+    draw_section(image, y, ansi_color_pairs, text);
+}
+
+/// Draw a grid of swatches starting at `y`, returning the `y` coordinate of the
+/// row following the section.
+fn draw_section<'a>(
+    image: &mut RgbImage,
+    y: u32,
+    cells: impl Iterator<Item = (Vec<Rgb<u8>>, &'a str)>,
+    text: Rgb<u8>,
+) -> u32 {
+    let mut count = 0;
+    for (index, (fills, label)) in cells.enumerate() {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = index as u32;
+        let x = PADDING + (index % COLUMNS) * (SWATCH_WIDTH + PADDING);
+        let y = y + (index / COLUMNS) * (CELL_HEIGHT + PADDING);
+        draw_cell(image, x, y, &fills, label, text);
+        count += 1;
+    }
+    y + section_height(count)
+}
+
+/// Draw a single swatch, split evenly between `fills`, with a label underneath.
+fn draw_cell(image: &mut RgbImage, x: u32, y: u32, fills: &[Rgb<u8>], label: &str, text: Rgb<u8>) {
+    #[allow(clippy::cast_possible_truncation)]
+    let fill_width = SWATCH_WIDTH / fills.len() as u32;
+    for (fill, fill_x) in fills.iter().zip((x..).step_by(fill_width as usize)) {
+        fill_rect(image, fill_x, y, fill_width, SWATCH_HEIGHT, *fill);
+    }
+    draw_text(image, x, y + SWATCH_HEIGHT + 6, label, text);
+}
+
+fn fill_rect(image: &mut RgbImage, x: u32, y: u32, width: u32, height: u32, color: Rgb<u8>) {
+    for py in y..y + height {
+        for px in x..x + width {
+            image.put_pixel(px, py, color);
+        }
+    }
+}
+
+/// Draw `text` with the built-in bitmap font. Characters without a glyph are
+/// rendered as blank space.
+fn draw_text(image: &mut RgbImage, x: u32, y: u32, text: &str, color: Rgb<u8>) {
+    for (c, glyph_x) in text.chars().zip((x..).step_by(GLYPH_ADVANCE as usize)) {
+        let Some(rows) = glyph(c) else { continue };
+        for (row, glyph_y) in rows.into_iter().zip((y..).step_by(FONT_SCALE as usize)) {
+            for column in 0..GLYPH_WIDTH {
+                if row & (1 << (GLYPH_WIDTH - 1 - column)) != 0 {
+                    let px = glyph_x + column * FONT_SCALE;
+                    fill_rect(image, px, glyph_y, FONT_SCALE, FONT_SCALE, color);
+                }
+            }
+        }
+    }
+}
+
+/// 5x9 bitmap glyphs (including two rows for descenders) for the characters used
+/// in identifiers. Each row is stored in the low five bits of a byte, most significant
+/// bit leftmost.
+const fn glyph(c: char) -> Option<[u8; 9]> {
+    Some(match c {
+        'a' => [0x00, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00, 0x00],
+        'b' => [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1e, 0x00, 0x00],
+        'c' => [0x00, 0x00, 0x0e, 0x10, 0x10, 0x11, 0x0e, 0x00, 0x00],
+        'd' => [0x01, 0x01, 0x0d, 0x13, 0x11, 0x11, 0x0f, 0x00, 0x00],
+        'e' => [0x00, 0x00, 0x0e, 0x11, 0x1f, 0x10, 0x0e, 0x00, 0x00],
+        'f' => [0x06, 0x09, 0x08, 0x1c, 0x08, 0x08, 0x08, 0x00, 0x00],
+        'g' => [0x00, 0x00, 0x0f, 0x11, 0x11, 0x11, 0x0f, 0x01, 0x0e],
+        'h' => [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00],
+        'i' => [0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x0e, 0x00, 0x00],
+        'j' => [0x02, 0x00, 0x06, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c],
+        'k' => [0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12, 0x00, 0x00],
+        'l' => [0x0c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00, 0x00],
+        'm' => [0x00, 0x00, 0x1a, 0x15, 0x15, 0x11, 0x11, 0x00, 0x00],
+        'n' => [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00],
+        'o' => [0x00, 0x00, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00, 0x00],
+        'p' => [0x00, 0x00, 0x1e, 0x11, 0x11, 0x11, 0x1e, 0x10, 0x10],
+        'q' => [0x00, 0x00, 0x0f, 0x11, 0x11, 0x11, 0x0f, 0x01, 0x01],
+        'r' => [0x00, 0x00, 0x16, 0x19, 0x10, 0x10, 0x10, 0x00, 0x00],
+        's' => [0x00, 0x00, 0x0e, 0x10, 0x0e, 0x01, 0x1e, 0x00, 0x00],
+        't' => [0x08, 0x08, 0x1c, 0x08, 0x08, 0x09, 0x06, 0x00, 0x00],
+        'u' => [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00, 0x00],
+        'v' => [0x00, 0x00, 0x11, 0x11, 0x11, 0x0a, 0x04, 0x00, 0x00],
+        'w' => [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0a, 0x00, 0x00],
+        'x' => [0x00, 0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x00, 0x00],
+        'y' => [0x00, 0x00, 0x11, 0x11, 0x11, 0x11, 0x0f, 0x01, 0x0e],
+        'z' => [0x00, 0x00, 0x1f, 0x02, 0x04, 0x08, 0x1f, 0x00, 0x00],
+        '0' => [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e, 0x00, 0x00],
+        '1' => [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00, 0x00],
+        '2' => [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f, 0x00, 0x00],
+        '3' => [0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e, 0x00, 0x00],
+        '4' => [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02, 0x00, 0x00],
+        '5' => [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e, 0x00, 0x00],
+        '6' => [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e, 0x00, 0x00],
+        '7' => [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08, 0x00, 0x00],
+        '8' => [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e, 0x00, 0x00],
+        '9' => [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c, 0x00, 0x00],
+        '_' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00],
+        _ => return None,
+    })
+}
@@ -24,11 +24,37 @@
 //! }
 //! ```
 //!
+//! Colors can also be looked up by path with the [`color!`] macro, which checks
+//! the path at compile time:
+//!
+//! ```rust
+//! const ACCENT: &catppuccin::Color = catppuccin::color!("mocha.mauve");
+//! let bright_red = catppuccin::color!("mocha.ansi.bright_red");
+//! ```
+//!
+//! For paths only known at runtime, such as those read from a config file, use
+//! [`Palette::lookup`] or parse a [`ColorRef`]:
+//!
+//! ```rust
+//! let bright_red = catppuccin::PALETTE.lookup("mocha.ansi.bright_red").unwrap();
+//! ```
+//!
 //! More examples can be found
 //! [here](https://github.com/catppuccin/rust/tree/main/examples).
 //!
 //! # Optional Features
 //!
+//! ## Allocation
+//!
+//! The `alloc` feature is enabled by default, and is required by the
+//! [`Lut`](crate::Lut) types, [`Swatches`](crate::Swatches), [`Ase`](crate::Ase), [`Color::ansi_paint`],
+//! and the `image` feature.
+//! Disable default features to build the crate without a global allocator:
+//!
+//! ```toml
+//! catppuccin = { version = "2", default-features = false }
+//! ```
+//!
 //! ## ANSI string painting
 //!
 //! Enable the `ansi-term` feature to add the
@@ -37,6 +63,31 @@
 //!
 //! Example: [`examples/term_grid.rs`](https://github.com/catppuccin/rust/blob/main/examples/term_grid.rs)
 //!
+//! ### Other ANSI painting libraries
+//!
+//! Enable the `colored`, `nu-ansi-term`, `owo-colors` or `yansi` features to enable
+//! the conversion of Catppuccin colors to the color types of
+//! [colored](https://crates.io/crates/colored),
+//! [nu-ansi-term](https://crates.io/crates/nu-ansi-term),
+//! [owo-colors](https://crates.io/crates/owo-colors) and
+//! [yansi](https://crates.io/crates/yansi) respectively, so that colors can be passed
+//! straight to each library's painting methods.
+//! owo-colors' `DynColor` trait is sealed, so `owo-colors` adds `owo_color` and
+//! `owo_on_color` methods to [`Color`](crate::Color) and [`AnsiColor`](crate::AnsiColor) in place of `OwoColorize::color`
+//! and `OwoColorize::on_color`.
+//! Each feature adds the respective crate as a dependency.
+//!
+//! Example: [`examples/painting.rs`](https://github.com/catppuccin/rust/blob/main/examples/painting.rs)
+//!
+//! ### anstyle
+//!
+//! Enable the `anstyle` feature to enable the conversion of Catppuccin colors to
+//! [`anstyle::Color`] instances, and to build a [`CliStyles`] set from a flavor
+//! for use with `clap`, `anstream`, `env_logger` and the rest of the `anstyle` ecosystem.
+//! This adds [anstyle](https://crates.io/crates/anstyle) as a dependency.
+//!
+//! Example: [`examples/anstyle.rs`](https://github.com/catppuccin/rust/blob/main/examples/anstyle.rs)
+//!
 //! ### Bevy
 //!
 //! Enable the `bevy` feature to enable the conversion of Catppuccin colors to
@@ -45,6 +96,17 @@
 //!
 //! Example: [`examples/bevy.rs`](https://github.com/catppuccin/rust/blob/main/examples/bevy.rs)
 //!
+//! ### Crossterm
+//!
+//! Enable the `crossterm` feature to enable the conversion of Catppuccin colors to
+//! [`crossterm::style::Color`] instances, and to add the [`Color::crossterm_styled`]
+//! and [`Flavor::crossterm_set_colors`] helpers.
+//! [`Theme::crossterm_styled`] and [`Theme::crossterm_set_colors`] style content
+//! with a theme's semantic [`Role`]s instead.
+//! This adds [crossterm](https://crates.io/crates/crossterm) as a dependency.
+//!
+//! Example: [`examples/crossterm.rs`](https://github.com/catppuccin/rust/blob/main/examples/crossterm.rs)
+//!
 //! ### CSS colors
 //!
 //! Enable the `css-colors` feature to enable the conversion of Catppuccin colors to
@@ -53,6 +115,26 @@
 //!
 //! Example: [`examples/css.rs`](https://github.com/catppuccin/rust/blob/main/examples/css.rs)
 //!
+//! ### egui
+//!
+//! Enable the `egui` feature to enable the conversion of Catppuccin colors to
+//! [`egui::Color32`] instances, and to build complete [`egui::Visuals`] and
+//! [`egui::Style`] themes from a flavor with [`Flavor::egui_visuals`] and
+//! [`Flavor::egui_style`].
+//! This adds [egui](https://crates.io/crates/egui) as a dependency.
+//!
+//! ### Embedded graphics
+//!
+//! Enable the `embedded-graphics` feature to enable the conversion of Catppuccin colors to
+//! [`Rgb565`](embedded_graphics_core::pixelcolor::Rgb565),
+//! [`Rgb666`](embedded_graphics_core::pixelcolor::Rgb666),
+//! [`Rgb888`](embedded_graphics_core::pixelcolor::Rgb888) and
+//! [`BinaryColor`](embedded_graphics_core::pixelcolor::BinaryColor) instances, as used by
+//! [embedded-graphics](https://crates.io/crates/embedded-graphics).
+//! [`FlavorColors::to_rgb565`] can be evaluated at compile time, so firmware
+//! doesn't have to convert colors at runtime.
+//! This adds [embedded-graphics-core](https://crates.io/crates/embedded-graphics-core) as a dependency.
+//!
 //! ### Iced
 //!
 //! Enable the `iced` feature to enable the conversion of Catppuccin colors to
@@ -61,6 +143,24 @@
 //!
 //! Example: [`examples/iced.rs`](https://github.com/catppuccin/rust/blob/main/examples/iced.rs)
 //!
+//! ### Image
+//!
+//! Enable the `image` feature to enable the conversion of Catppuccin colors to
+//! [`image::Rgb`] and [`image::Rgba`] instances, and to render swatch images of
+//! a flavor or the whole palette with [`Flavor::swatch_image`] and [`Palette::swatch_image`].
+//! This adds [image](https://crates.io/crates/image) as a dependency.
+//!
+//! Example: [`examples/image.rs`](https://github.com/catppuccin/rust/blob/main/examples/image.rs)
+//!
+//! ### Palette
+//!
+//! Enable the `palette` feature to enable the conversion of Catppuccin colors to
+//! [`palette::Srgb`], [`palette::LinSrgb`], [`palette::Hsl`], [`palette::Oklch`]
+//! and [`palette::Lab`] instances, for use with `palette`'s mixing and gamut functions.
+//! This adds [palette](https://crates.io/crates/palette) as a dependency.
+//!
+//! Example: [`examples/palette.rs`](https://github.com/catppuccin/rust/blob/main/examples/palette.rs)
+//!
 //! ### Ratatui
 //!
 //! Enable the `ratatui` feature to enable the conversion of Catppuccin colors to
@@ -72,23 +172,64 @@
 //! ### Serde
 //!
 //! Enable the `serde` feature to enable the serialization of Catppuccin's palette,
-//! flavor, and color types.
+//! flavor, and color types. `PaletteFile` reads and writes palettes in the schema
+//! of the upstream `palette.json`, including its version, and `Theme` loads theme
+//! files that override a flavor's colors and map semantic roles, from TOML, YAML
+//! or any other format supported by serde.
 //! This adds [serde](https://crates.io/crates/serde) as a dependency.
 //!
-//! Example: [`examples/serde.rs`](https://github.com/catppuccin/rust/blob/main/examples/serde.rs)
+//! Example: [`examples/serde.rs`](https://github.com/catppuccin/rust/blob/main/examples/serde.rs) and [`examples/theme.rs`](https://github.com/catppuccin/rust/blob/main/examples/theme.rs)
 #![no_std]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
-use core::{fmt, marker::PhantomData, ops::Index, str::FromStr};
+use core::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+pub use crate::base16::{Base16Format, Base16Scheme, Base16System};
+pub use crate::lookup::{ColorRef, ColorRefSegment, ParseColorRefError, ResolvedColor};
+#[cfg(feature = "alloc")]
+pub use crate::lut::{HaldClut, Lut, LutMapping};
+pub use crate::recolor::{ColorSelection, Dither, Metric, Recolor};
+#[cfg(feature = "serde")]
+pub use crate::schema::PaletteFile;
+pub use crate::schema::{PaletteVersion, ParsePaletteVersionError};
+pub use crate::stylesheet::{Css, Preprocessor, PreprocessorVariables, Tailwind, TailwindFormat};
+#[cfg(feature = "alloc")]
+pub use crate::swatches::{Ase, AseGroup, AseSwatch, ParseAseError, SwatchFormat, Swatches};
+pub use crate::terminal::{TerminalFormat, TerminalTheme};
+pub use crate::theme::{Role, Theme, ThemeSchema};
+pub use crate::tokens::{DesignTokenFormat, DesignTokens};
+
+#[cfg(feature = "anstyle")]
+pub use crate::anstyle::CliStyles;
 
 include!(concat!(env!("OUT_DIR"), "/generated_palette.rs"));
 
+mod base16;
+mod lookup;
+#[cfg(feature = "alloc")]
+mod lut;
+mod parse;
+mod recolor;
+mod schema;
+mod stylesheet;
+#[cfg(feature = "alloc")]
+mod swatches;
+mod terminal;
+mod theme;
+mod tokens;
+
 /// The top-level type that encompasses the Catppuccin palette data structure.
 /// Primarily used via the [`PALETTE`] constant.
 ///
 /// Can be iterated over, in which case the flavors are yielded in the canonical order:
 /// Latte, Frappé, Macchiato, Mocha.
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Palette {
     /// The light flavor.
@@ -102,7 +243,9 @@ pub struct Palette {
 }
 
 /// Enum of all four flavors of Catppuccin. Can be used to index [`Palette`].
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///
+/// Ordered by each flavor's order in the palette: Latte, Frappé, Macchiato, Mocha.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FlavorName {
     /// The light flavor.
@@ -119,8 +262,8 @@ pub enum FlavorName {
 /// An iterator over flavors in the palette.
 /// Obtained via [`Palette::iter()`].
 pub struct FlavorIterator<'a> {
+    palette: &'a Palette,
     current: usize,
-    phantom: PhantomData<&'a ()>,
 }
 
 /// Color represented as individual red, green, and blue channels.
@@ -140,6 +283,27 @@ pub struct Rgb {
 pub struct Hex(Rgb);
 
 /// Color represented as individual hue (0-359), saturation (0-1), and lightness (0-1) channels.
+///
+/// The channels of the colors in [`PALETTE`] are exactly those of the upstream
+/// `palette.json`.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::PALETTE;
+///
+/// let upstream = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/palette.json"));
+/// let upstream: serde_json::Value = serde_json::from_str(upstream)?;
+/// for flavor in &PALETTE {
+///     for color in flavor {
+///         let hsl = &upstream[flavor.identifier()]["colors"][color.identifier()]["hsl"];
+///         assert_eq!(Some(color.hsl.h), hsl["h"].as_f64());
+///         assert_eq!(Some(color.hsl.s), hsl["s"].as_f64());
+///         assert_eq!(Some(color.hsl.l), hsl["l"].as_f64());
+///     }
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hsl {
@@ -201,6 +365,13 @@ pub struct ColorIterator<'a> {
     current: usize,
 }
 
+/// An iterator over the accent colors in a flavor.
+/// Obtained via [`Flavor::accents()`] or [`FlavorColors::accents()`].
+pub struct AccentIterator<'a> {
+    colors: &'a FlavorColors,
+    current: usize,
+}
+
 /// An iterator over the ANSI colors in a flavor.
 ///
 /// Defaults to ascending order by ANSI code 0 -> 16.
@@ -225,11 +396,22 @@ impl Palette {
     }
 
     /// Create an iterator over the flavors in the palette.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::PALETTE;
+    ///
+    /// let mut palette = PALETTE;
+    /// palette.mocha.colors.base = palette.mocha.colors.crust;
+    /// let mocha = palette.iter().last().unwrap();
+    /// assert_eq!(mocha.colors.base, PALETTE.mocha.colors.crust);
+    /// ```
     #[must_use]
     pub const fn iter(&self) -> FlavorIterator<'_> {
         FlavorIterator {
+            palette: self,
             current: 0,
-            phantom: PhantomData,
         }
     }
 }
@@ -247,6 +429,17 @@ impl Index<FlavorName> for Palette {
     }
 }
 
+impl IndexMut<FlavorName> for Palette {
+    fn index_mut(&mut self, index: FlavorName) -> &mut Self::Output {
+        match index {
+            FlavorName::Latte => &mut self.latte,
+            FlavorName::Frappe => &mut self.frappe,
+            FlavorName::Macchiato => &mut self.macchiato,
+            FlavorName::Mocha => &mut self.mocha,
+        }
+    }
+}
+
 impl Palette {
     /// Get a flavor by name.
     ///
@@ -272,34 +465,70 @@ impl fmt::Display for Hex {
 
 #[cfg(feature = "serde")]
 mod _hex {
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use core::fmt;
 
-    use crate::{Hex, Rgb};
+    use serde::{
+        de::{self, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
 
-    use alloc::string::String;
-    use alloc::string::ToString;
+    use crate::{Hex, Rgb};
 
     impl Serialize for Hex {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            serializer.serialize_str(&self.to_string())
+            serializer.collect_str(self)
         }
     }
 
+    struct HexVisitor;
+
+    impl Visitor<'_> for HexVisitor {
+        type Value = Hex;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a six-digit hex color string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let digits = v.strip_prefix('#').unwrap_or(v);
+            if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+            }
+            let [_, r, g, b] = u32::from_str_radix(digits, 16)
+                .map_err(E::custom)?
+                .to_be_bytes();
+            Ok(Hex(Rgb { r, g, b }))
+        }
+    }
+
+    /// Deserializes a six-digit hex color string, with or without a leading `#`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::Hex;
+    ///
+    /// let hex: Hex = serde_json::from_str(r##""#1e1e2e""##).unwrap();
+    /// assert_eq!(hex, Hex::from((0x1e, 0x1e, 0x2e)));
+    /// assert_eq!(serde_json::from_str::<Hex>(r#""1e1e2e""#).unwrap(), hex);
+    ///
+    /// for invalid in ["#fff", "#1234567", "##1e1e2e", "#1e1e2g", "+1e1e2e"] {
+    ///     let invalid = serde_json::Value::from(invalid);
+    ///     assert!(serde_json::from_value::<Hex>(invalid).is_err());
+    /// }
+    /// ```
     impl<'de> Deserialize<'de> for Hex {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: Deserializer<'de>,
         {
-            let hex: String = Deserialize::deserialize(deserializer)?;
-            let hex: u32 = u32::from_str_radix(hex.trim_start_matches('#'), 16)
-                .map_err(serde::de::Error::custom)?;
-            let r = ((hex >> 16) & 0xff) as u8;
-            let g = ((hex >> 8) & 0xff) as u8;
-            let b = (hex & 0xff) as u8;
-            Ok(Self(Rgb { r, g, b }))
+            deserializer.deserialize_str(HexVisitor)
         }
     }
 }
@@ -316,7 +545,7 @@ impl fmt::Display for FlavorName {
 }
 
 /// Error type for parsing a [`FlavorName`] from a string.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ParseFlavorNameError;
 impl core::error::Error for ParseFlavorNameError {}
 impl core::fmt::Display for ParseFlavorNameError {
@@ -328,21 +557,89 @@ impl core::fmt::Display for ParseFlavorNameError {
     }
 }
 
+/// Error type for parsing a [`FlavorName`] with
+/// [`FlavorName::parse_with_suggestion`], which suggests a similar name.
+///
+/// Example:
+///
+/// ```rust
+/// let error = catppuccin::FlavorName::parse_with_suggestion("machiato").unwrap_err();
+/// assert_eq!(error.suggestion(), Some("macchiato"));
+/// assert!(error.to_string().ends_with(" (did you mean `macchiato`?)"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownFlavorNameError {
+    suggestion: Option<&'static str>,
+}
+impl core::error::Error for UnknownFlavorNameError {}
+impl core::fmt::Display for UnknownFlavorNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ParseFlavorNameError.fmt(f)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
+    }
+}
+
+impl UnknownFlavorNameError {
+    /// The identifier of the flavor most similar to the input, if the input looks
+    /// like a typo of one.
+    #[must_use]
+    pub const fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+impl From<UnknownFlavorNameError> for ParseFlavorNameError {
+    fn from(_: UnknownFlavorNameError) -> Self {
+        Self
+    }
+}
+
+/// Parses a flavor from its identifier or display name, ignoring case,
+/// whitespace, underscores, hyphens and accents.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::FlavorName;
+///
+/// assert_eq!("Mocha".parse(), Ok(FlavorName::Mocha));
+/// assert_eq!("FRAPPÉ".parse(), Ok(FlavorName::Frappe));
+/// ```
 impl FromStr for FlavorName {
     type Err = ParseFlavorNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "latte" => Ok(Self::Latte),
-            "frappe" | "frappé" => Ok(Self::Frappe),
-            "macchiato" => Ok(Self::Macchiato),
-            "mocha" => Ok(Self::Mocha),
-            _ => Err(ParseFlavorNameError),
-        }
+        Self::parse_with_suggestion(s).map_err(ParseFlavorNameError::from)
     }
 }
 
 impl FlavorName {
+    /// Parse a flavor name like [`FromStr`], but with a suggestion of a similar
+    /// name in the error if parsing fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a flavor name.
+    pub fn parse_with_suggestion(s: &str) -> Result<Self, UnknownFlavorNameError> {
+        parse::parse_name(
+            s,
+            &[
+                ("latte", Self::Latte),
+                ("frappe", Self::Frappe),
+                ("macchiato", Self::Macchiato),
+                ("mocha", Self::Mocha),
+            ],
+            &[],
+        )
+        .map_err(|suggestion| UnknownFlavorNameError { suggestion })
+    }
+
+    /// All flavor names, in order.
+    pub const ALL: [Self; 4] = [Self::Latte, Self::Frappe, Self::Macchiato, Self::Mocha];
+
     /// Get the flavor's identifier; the lowercase key used to identify the flavor.
     /// This differs from `to_string` in that it's intended for machine usage
     /// rather than presentation.
@@ -374,6 +671,15 @@ impl FlavorColors {
             current: 0,
         }
     }
+
+    /// Create an iterator over the accent colors in the flavor.
+    #[must_use]
+    pub const fn accents(&self) -> AccentIterator<'_> {
+        AccentIterator {
+            colors: self,
+            current: 0,
+        }
+    }
 }
 
 impl FlavorAnsiColors {
@@ -408,10 +714,10 @@ impl<'a> Iterator for FlavorIterator<'a> {
     type Item = &'a Flavor;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current >= PALETTE.all_flavors().len() {
+        if self.current >= self.palette.all_flavors().len() {
             None
         } else {
-            let flavor = PALETTE.all_flavors()[self.current];
+            let flavor = self.palette.all_flavors()[self.current];
             self.current += 1;
             Some(flavor)
         }
@@ -432,6 +738,20 @@ impl<'a> Iterator for ColorIterator<'a> {
     }
 }
 
+impl<'a> Iterator for AccentIterator<'a> {
+    type Item = &'a Color;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= AccentName::ALL.len() {
+            None
+        } else {
+            let color = self.colors.get_accent(AccentName::ALL[self.current]);
+            self.current += 1;
+            Some(color)
+        }
+    }
+}
+
 impl<'a> Iterator for AnsiColorIterator<'a> {
     type Item = &'a AnsiColor;
 
@@ -503,6 +823,35 @@ impl Flavor {
         self.colors.iter()
     }
 
+    /// Create an iterator over the accent colors in the flavor.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// let accents = catppuccin::PALETTE.mocha.accents();
+    /// assert!(accents.map(|color| color.accent).eq([true; 14]));
+    /// ```
+    #[must_use]
+    pub const fn accents(&self) -> AccentIterator<'_> {
+        self.colors.accents()
+    }
+
+    /// Get an accent color by name.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{AccentName, ColorName, PALETTE};
+    ///
+    /// let accent: AccentName = "mauve".parse().unwrap();
+    /// assert_eq!(PALETTE.mocha.accent(accent), &PALETTE.mocha.colors.mauve);
+    /// assert!(AccentName::try_from(ColorName::Crust).is_err());
+    /// ```
+    #[must_use]
+    pub const fn accent(&self, name: AccentName) -> &Color {
+        self.colors.get_accent(name)
+    }
+
     /// Equivalent to [`<flavor>.name.identifier()`](FlavorName::identifier).
     #[must_use]
     pub const fn identifier(&self) -> &'static str {
@@ -519,8 +868,23 @@ impl<'a> IntoIterator for &'a Flavor {
     }
 }
 
-/// Error type for parsing a [`ColorName`] from a string.
-#[derive(Debug, PartialEq, Eq)]
+/// Error type for parsing a [`ColorName`], [`AnsiColorName`],
+/// [`AnsiColorPairName`] or [`AccentName`] from a string.
+///
+/// Names are parsed from their identifiers, display names or common aliases such
+/// as `lav`, ignoring case, whitespace, underscores and hyphens.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{AnsiColorName, ColorName};
+///
+/// assert_eq!("Surface 0".parse(), Ok(ColorName::Surface0));
+/// assert_eq!("surface-0".parse(), Ok(ColorName::Surface0));
+/// assert_eq!("lav".parse(), Ok(ColorName::Lavender));
+/// assert_eq!("bright-red".parse(), Ok(AnsiColorName::BrightRed));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ParseColorNameError;
 impl core::error::Error for ParseColorNameError {}
 impl core::fmt::Display for ParseColorNameError {
@@ -529,6 +893,76 @@ impl core::fmt::Display for ParseColorNameError {
     }
 }
 
+/// Error type for parsing a color name with `parse_with_suggestion`, such as
+/// [`ColorName::parse_with_suggestion`], which suggests a similar name.
+///
+/// Example:
+///
+/// ```rust
+/// let error = catppuccin::ColorName::parse_with_suggestion("lavendar").unwrap_err();
+/// assert_eq!(error.suggestion(), Some("lavender"));
+/// assert_eq!(error.to_string(), "invalid color identifier (did you mean `lavender`?)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownColorNameError {
+    suggestion: Option<&'static str>,
+}
+impl core::error::Error for UnknownColorNameError {}
+impl core::fmt::Display for UnknownColorNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ParseColorNameError.fmt(f)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
+    }
+}
+
+impl UnknownColorNameError {
+    /// The identifier of the color most similar to the input, if the input looks
+    /// like a typo of one.
+    #[must_use]
+    pub const fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+impl From<UnknownColorNameError> for ParseColorNameError {
+    fn from(_: UnknownColorNameError) -> Self {
+        Self
+    }
+}
+
+/// Error type for converting an ANSI code to an [`AnsiColorName`]; only codes
+/// 0-15 have names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromAnsiCodeError(u8);
+impl core::error::Error for TryFromAnsiCodeError {}
+impl core::fmt::Display for TryFromAnsiCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid ANSI color code {}, expected 0-15", self.0)
+    }
+}
+
+/// Error type for converting a [`ColorName`] that is not an accent color to an
+/// [`AccentName`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromColorNameError(ColorName);
+impl core::error::Error for TryFromColorNameError {}
+impl core::fmt::Display for TryFromColorNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not an accent color", self.0.identifier())
+    }
+}
+
+impl Index<AccentName> for Flavor {
+    type Output = Color;
+
+    fn index(&self, index: AccentName) -> &Self::Output {
+        self.colors.index(index)
+    }
+}
+
 impl Index<ColorName> for Flavor {
     type Output = Color;
 
@@ -574,6 +1008,64 @@ impl From<(f64, f64, f64)> for Hsl {
     }
 }
 
+impl From<Rgb> for Hsl {
+    fn from(value: Rgb) -> Self {
+        let [red, green, blue] = [value.r, value.g, value.b].map(|c| f64::from(c) / 255.0);
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+        let lightness = f64::midpoint(max, min);
+        if delta == 0.0 {
+            return Self {
+                h: 0.0,
+                s: 0.0,
+                l: lightness,
+            };
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        #[allow(clippy::float_cmp)]
+        let sector = if max == red {
+            let sector = (green - blue) / delta;
+            if sector < 0.0 {
+                sector + 6.0
+            } else {
+                sector
+            }
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+        Self {
+            h: sector * 60.0,
+            s: saturation,
+            l: lightness,
+        }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from(value: Hsl) -> Self {
+        let hue = value.h % 360.0;
+        let sector = if hue < 0.0 { hue + 360.0 } else { hue } / 60.0;
+        let chroma = (1.0 - (2.0 * value.l - 1.0).abs()) * value.s;
+        let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let channels = match sector as u8 {
+            0 => [chroma, second, 0.0],
+            1 => [second, chroma, 0.0],
+            2 => [0.0, chroma, second],
+            3 => [0.0, second, chroma],
+            4 => [second, 0.0, chroma],
+            _ => [chroma, 0.0, second],
+        };
+        let lightness = value.l - chroma / 2.0;
+        let [r, g, b] = channels.map(|c| (((c + lightness) * 255.0).clamp(0.0, 255.0) + 0.5) as u8);
+        Self { r, g, b }
+    }
+}
+
 #[cfg(feature = "ansi-term")]
 mod ansi_term {
     use crate::{AnsiColor, Color};
@@ -608,6 +1100,123 @@ mod ansi_term {
     }
 }
 
+#[cfg(feature = "anstyle")]
+mod anstyle {
+    use crate::{AnsiColor, AnsiColorName, Color, Flavor};
+
+    impl From<Color> for anstyle::RgbColor {
+        fn from(value: Color) -> Self {
+            Self(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<Color> for anstyle::Color {
+        fn from(value: Color) -> Self {
+            Self::Rgb(value.into())
+        }
+    }
+
+    impl From<AnsiColor> for anstyle::RgbColor {
+        fn from(value: AnsiColor) -> Self {
+            Self(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    /// Converts by [`name`](AnsiColor::name), so the [`code`](AnsiColor::code) is
+    /// not used.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// let mut red = catppuccin::PALETTE.mocha.ansi_colors.red;
+    /// red.code = 196;
+    /// assert_eq!(anstyle::AnsiColor::from(red), anstyle::AnsiColor::Red);
+    /// ```
+    impl From<AnsiColor> for anstyle::AnsiColor {
+        fn from(value: AnsiColor) -> Self {
+            match value.name {
+                AnsiColorName::Black => Self::Black,
+                AnsiColorName::Red => Self::Red,
+                AnsiColorName::Green => Self::Green,
+                AnsiColorName::Yellow => Self::Yellow,
+                AnsiColorName::Blue => Self::Blue,
+                AnsiColorName::Magenta => Self::Magenta,
+                AnsiColorName::Cyan => Self::Cyan,
+                AnsiColorName::White => Self::White,
+                AnsiColorName::BrightBlack => Self::BrightBlack,
+                AnsiColorName::BrightRed => Self::BrightRed,
+                AnsiColorName::BrightGreen => Self::BrightGreen,
+                AnsiColorName::BrightYellow => Self::BrightYellow,
+                AnsiColorName::BrightBlue => Self::BrightBlue,
+                AnsiColorName::BrightMagenta => Self::BrightMagenta,
+                AnsiColorName::BrightCyan => Self::BrightCyan,
+                AnsiColorName::BrightWhite => Self::BrightWhite,
+            }
+        }
+    }
+
+    /// A set of [`anstyle::Style`]s for command-line output, derived from a [`Flavor`].
+    /// Obtained via [`Flavor::cli_styles`].
+    ///
+    /// The fields line up with the styles accepted by `clap::builder::Styles`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// let styles = catppuccin::PALETTE.mocha.cli_styles();
+    /// let clap_styles = clap::builder::Styles::styled()
+    ///     .header(styles.header)
+    ///     .usage(styles.usage)
+    ///     .literal(styles.literal)
+    ///     .placeholder(styles.placeholder)
+    ///     .error(styles.error)
+    ///     .valid(styles.valid)
+    ///     .invalid(styles.invalid);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct CliStyles {
+        /// General heading style, e.g. `Usage:` and `Options:`.
+        pub header: anstyle::Style,
+        /// Usage heading style.
+        pub usage: anstyle::Style,
+        /// Literal command-line syntax, e.g. `--help`.
+        pub literal: anstyle::Style,
+        /// Descriptions within command-line syntax, e.g. `<FILE>`.
+        pub placeholder: anstyle::Style,
+        /// Error heading style.
+        pub error: anstyle::Style,
+        /// Highlight for correct input.
+        pub valid: anstyle::Style,
+        /// Highlight for incorrect input.
+        pub invalid: anstyle::Style,
+    }
+
+    impl Flavor {
+        /// Build a [`CliStyles`] set for this flavor.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// let styles = catppuccin::PALETTE.mocha.cli_styles();
+        /// println!("{}error:{} something went wrong", styles.error, styles.error.render_reset());
+        /// ```
+        #[must_use]
+        pub fn cli_styles(&self) -> CliStyles {
+            let style = |color: Color| anstyle::Style::new().fg_color(Some(color.into()));
+            let colors = &self.colors;
+            CliStyles {
+                header: style(colors.mauve).bold().underline(),
+                usage: style(colors.mauve).bold().underline(),
+                literal: style(colors.blue).bold(),
+                placeholder: style(colors.peach),
+                error: style(colors.red).bold(),
+                valid: style(colors.green).bold(),
+                invalid: style(colors.yellow).bold(),
+            }
+        }
+    }
+}
+
 #[cfg(feature = "bevy")]
 mod bevy {
     use crate::{AnsiColor, Color};
@@ -627,6 +1236,174 @@ mod bevy {
     }
 }
 
+#[cfg(feature = "colored")]
+mod colored {
+    use crate::{AnsiColor, Color};
+
+    impl From<Color> for colored::Color {
+        fn from(value: Color) -> Self {
+            Self::TrueColor {
+                r: value.rgb.r,
+                g: value.rgb.g,
+                b: value.rgb.b,
+            }
+        }
+    }
+
+    impl From<AnsiColor> for colored::Color {
+        fn from(value: AnsiColor) -> Self {
+            Self::TrueColor {
+                r: value.rgb.r,
+                g: value.rgb.g,
+                b: value.rgb.b,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+mod crossterm {
+    use crate::{AnsiColor, Color, Flavor, Role, Theme};
+    use core::fmt::Display;
+    use crossterm::style::{Colors, ContentStyle, SetColors, StyledContent};
+
+    impl From<Color> for crossterm::style::Color {
+        fn from(value: Color) -> Self {
+            Self::Rgb {
+                r: value.rgb.r,
+                g: value.rgb.g,
+                b: value.rgb.b,
+            }
+        }
+    }
+
+    impl From<AnsiColor> for crossterm::style::Color {
+        fn from(value: AnsiColor) -> Self {
+            Self::AnsiValue(value.code)
+        }
+    }
+
+    impl Color {
+        /// Styles the given content with this color as the foreground color à la
+        /// [crossterm](https://docs.rs/crossterm/latest/crossterm/style/trait.Stylize.html).
+        #[must_use]
+        pub fn crossterm_styled<D: Display>(&self, content: D) -> StyledContent<D> {
+            StyledContent::new(
+                ContentStyle {
+                    foreground_color: Some((*self).into()),
+                    ..ContentStyle::new()
+                },
+                content,
+            )
+        }
+    }
+
+    impl AnsiColor {
+        /// Styles the given content with this color as the foreground color à la
+        /// [crossterm](https://docs.rs/crossterm/latest/crossterm/style/trait.Stylize.html).
+        #[must_use]
+        pub fn crossterm_styled<D: Display>(&self, content: D) -> StyledContent<D> {
+            StyledContent::new(
+                ContentStyle {
+                    foreground_color: Some((*self).into()),
+                    ..ContentStyle::new()
+                },
+                content,
+            )
+        }
+    }
+
+    impl Flavor {
+        /// Get the flavor's default foreground (`text`) and background (`base`)
+        /// colors as crossterm [`Colors`].
+        #[must_use]
+        pub fn crossterm_colors(&self) -> Colors {
+            Colors::new(self.colors.text.into(), self.colors.base.into())
+        }
+
+        /// Get a [`SetColors`] command that sets the terminal's foreground and
+        /// background to the flavor's `text` and `base` colors.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// use crossterm::{execute, style::ResetColor};
+        ///
+        /// let mocha = catppuccin::PALETTE.mocha;
+        /// execute!(
+        ///     std::io::stdout(),
+        ///     mocha.crossterm_set_colors(),
+        ///     crossterm::style::Print("Hello, Mocha!"),
+        ///     ResetColor,
+        /// )?;
+        /// # Ok::<(), std::io::Error>(())
+        /// ```
+        #[must_use]
+        pub fn crossterm_set_colors(&self) -> SetColors {
+            SetColors(self.crossterm_colors())
+        }
+
+        /// Styles the given content with the flavor's `text` color on its `base` background.
+        #[must_use]
+        pub fn crossterm_styled<D: Display>(&self, content: D) -> StyledContent<D> {
+            let colors = self.crossterm_colors();
+            StyledContent::new(
+                ContentStyle {
+                    foreground_color: colors.foreground,
+                    background_color: colors.background,
+                    ..ContentStyle::new()
+                },
+                content,
+            )
+        }
+    }
+
+    impl Theme {
+        /// Get the theme's [`Role::Text`] and [`Role::Background`] colors as
+        /// crossterm [`Colors`].
+        #[must_use]
+        pub fn crossterm_colors(&self) -> Colors {
+            Colors::new(self[Role::Text].into(), self[Role::Background].into())
+        }
+
+        /// Get a [`SetColors`] command that sets the terminal's foreground and
+        /// background to the theme's [`Role::Text`] and [`Role::Background`]
+        /// colors.
+        #[must_use]
+        pub fn crossterm_set_colors(&self) -> SetColors {
+            SetColors(self.crossterm_colors())
+        }
+
+        /// Styles the given content with the theme's color for `role` on its
+        /// [`Role::Background`] color.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// use catppuccin::{ColorName, Role, Theme, PALETTE};
+        /// use crossterm::style::Color;
+        ///
+        /// let mut theme = Theme::new(PALETTE.mocha);
+        /// theme.set_role(Role::Error, ColorName::Maroon);
+        ///
+        /// let styled = theme.crossterm_styled(Role::Error, "oh no");
+        /// assert_eq!(styled.style().foreground_color, Some(Color::Rgb { r: 235, g: 160, b: 172 }));
+        /// assert_eq!(styled.style().background_color, Some(Color::Rgb { r: 30, g: 30, b: 46 }));
+        /// ```
+        #[must_use]
+        pub fn crossterm_styled<D: Display>(&self, role: Role, content: D) -> StyledContent<D> {
+            StyledContent::new(
+                ContentStyle {
+                    foreground_color: Some(self[role].into()),
+                    background_color: Some(self[Role::Background].into()),
+                    ..ContentStyle::new()
+                },
+                content,
+            )
+        }
+    }
+}
+
 #[cfg(feature = "css-colors")]
 mod css_colors {
     use crate::{AnsiColor, Color};
@@ -674,6 +1451,260 @@ mod css_colors {
     }
 }
 
+#[cfg(feature = "egui")]
+mod egui {
+    use crate::{AnsiColor, Color, Flavor};
+
+    impl From<Color> for egui::Color32 {
+        fn from(value: Color) -> Self {
+            Self::from_rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<AnsiColor> for egui::Color32 {
+        fn from(value: AnsiColor) -> Self {
+            Self::from_rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl Flavor {
+        /// Build a complete set of [`egui::Visuals`] for this flavor, following the
+        /// mappings of the official [Catppuccin for egui](https://github.com/catppuccin/egui) port.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// let ctx = egui::Context::default();
+        /// ctx.set_visuals(catppuccin::PALETTE.mocha.egui_visuals());
+        /// assert!(ctx.style().visuals.dark_mode);
+        /// ```
+        #[must_use]
+        pub fn egui_visuals(&self) -> egui::Visuals {
+            let colors = &self.colors;
+            let old = if self.dark {
+                egui::Visuals::dark()
+            } else {
+                egui::Visuals::light()
+            };
+            let selection_alpha = if self.dark { 0.2 } else { 0.4 };
+            let widget_visuals =
+                |old: egui::style::WidgetVisuals, bg_fill: Color| egui::style::WidgetVisuals {
+                    bg_fill: bg_fill.into(),
+                    weak_bg_fill: bg_fill.into(),
+                    bg_stroke: egui::Stroke {
+                        color: colors.overlay1.into(),
+                        ..old.bg_stroke
+                    },
+                    fg_stroke: egui::Stroke {
+                        color: colors.text.into(),
+                        ..old.fg_stroke
+                    },
+                    ..old
+                };
+
+            egui::Visuals {
+                dark_mode: self.dark,
+                override_text_color: Some(colors.text.into()),
+                hyperlink_color: colors.rosewater.into(),
+                faint_bg_color: colors.surface0.into(),
+                extreme_bg_color: colors.crust.into(),
+                code_bg_color: colors.mantle.into(),
+                warn_fg_color: colors.peach.into(),
+                error_fg_color: colors.maroon.into(),
+                window_fill: colors.base.into(),
+                panel_fill: colors.base.into(),
+                window_stroke: egui::Stroke {
+                    color: colors.overlay1.into(),
+                    ..old.window_stroke
+                },
+                widgets: egui::style::Widgets {
+                    noninteractive: widget_visuals(old.widgets.noninteractive, colors.base),
+                    inactive: widget_visuals(old.widgets.inactive, colors.surface0),
+                    hovered: widget_visuals(old.widgets.hovered, colors.surface2),
+                    active: widget_visuals(old.widgets.active, colors.surface1),
+                    open: widget_visuals(old.widgets.open, colors.surface0),
+                },
+                selection: egui::style::Selection {
+                    bg_fill: egui::Color32::from(colors.blue).linear_multiply(selection_alpha),
+                    stroke: egui::Stroke {
+                        color: colors.overlay1.into(),
+                        ..old.selection.stroke
+                    },
+                },
+                window_shadow: egui::epaint::Shadow {
+                    color: colors.base.into(),
+                    ..old.window_shadow
+                },
+                popup_shadow: egui::epaint::Shadow {
+                    color: colors.base.into(),
+                    ..old.popup_shadow
+                },
+                ..old
+            }
+        }
+
+        /// Build an [`egui::Style`] using the default spacing and text styles,
+        /// with the [`egui::Visuals`] from [`Flavor::egui_visuals`].
+        #[must_use]
+        pub fn egui_style(&self) -> egui::Style {
+            egui::Style {
+                visuals: self.egui_visuals(),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+mod embedded_graphics {
+    use crate::{AnsiColor, Color, FlavorAnsiColors, FlavorColors, Rgb};
+    use embedded_graphics_core::pixelcolor::{BinaryColor, Rgb565, Rgb666, Rgb888};
+
+    /// Scale an 8-bit channel down to `max`, rounding to the nearest value.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn scale(channel: u8, max: u16) -> u8 {
+        ((channel as u16 * max + 127) / 255) as u8
+    }
+
+    impl Rgb {
+        /// Convert the color to an [`Rgb565`], rounding each channel to the nearest value.
+        /// Unlike the equivalent [`From`] implementation, this can be used in const contexts.
+        #[must_use]
+        pub const fn to_rgb565(self) -> Rgb565 {
+            Rgb565::new(scale(self.r, 31), scale(self.g, 63), scale(self.b, 31))
+        }
+
+        /// Convert the color to an [`Rgb666`], rounding each channel to the nearest value.
+        /// Unlike the equivalent [`From`] implementation, this can be used in const contexts.
+        #[must_use]
+        pub const fn to_rgb666(self) -> Rgb666 {
+            Rgb666::new(scale(self.r, 63), scale(self.g, 63), scale(self.b, 63))
+        }
+    }
+
+    impl FlavorColors {
+        /// Get an array of the colors in the flavor as [`Rgb565`], in the same
+        /// order as [`FlavorColors::all_colors`].
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// use catppuccin::PALETTE;
+        /// use embedded_graphics_core::pixelcolor::Rgb565;
+        ///
+        /// // computed at compile time
+        /// const MOCHA: [Rgb565; 26] = PALETTE.mocha.colors.to_rgb565();
+        ///
+        /// let mauve = PALETTE.mocha.colors.mauve;
+        /// assert_eq!(MOCHA[mauve.order as usize], mauve.into());
+        /// ```
+        #[must_use]
+        pub const fn to_rgb565(&self) -> [Rgb565; 26] {
+            let colors = self.all_colors();
+            let mut table = [Rgb565::new(0, 0, 0); 26];
+            let mut i = 0;
+            while i < colors.len() {
+                table[i] = colors[i].rgb.to_rgb565();
+                i += 1;
+            }
+            table
+        }
+    }
+
+    impl FlavorAnsiColors {
+        /// Get an array of the ANSI colors in the flavor as [`Rgb565`], in the same
+        /// order as [`FlavorAnsiColors::all_ansi_colors`].
+        #[must_use]
+        pub const fn to_rgb565(&self) -> [Rgb565; 16] {
+            let colors = self.all_ansi_colors();
+            let mut table = [Rgb565::new(0, 0, 0); 16];
+            let mut i = 0;
+            while i < colors.len() {
+                table[i] = colors[i].rgb.to_rgb565();
+                i += 1;
+            }
+            table
+        }
+    }
+
+    impl From<Rgb> for Rgb565 {
+        fn from(value: Rgb) -> Self {
+            value.to_rgb565()
+        }
+    }
+
+    impl From<Rgb> for Rgb666 {
+        fn from(value: Rgb) -> Self {
+            value.to_rgb666()
+        }
+    }
+
+    impl From<Rgb> for Rgb888 {
+        fn from(value: Rgb) -> Self {
+            Self::new(value.r, value.g, value.b)
+        }
+    }
+
+    /// Light colors are [`BinaryColor::On`] and dark colors are [`BinaryColor::Off`],
+    /// judged by their luma.
+    impl From<Rgb> for BinaryColor {
+        fn from(value: Rgb) -> Self {
+            let luma =
+                (299 * u32::from(value.r) + 587 * u32::from(value.g) + 114 * u32::from(value.b))
+                    / 1000;
+            (luma >= 128).into()
+        }
+    }
+
+    impl From<Color> for Rgb565 {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<Color> for Rgb666 {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<Color> for Rgb888 {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<Color> for BinaryColor {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for Rgb565 {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for Rgb666 {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for Rgb888 {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for BinaryColor {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+}
+
 #[cfg(feature = "iced")]
 mod iced {
     use crate::{AnsiColor, Color};
@@ -691,6 +1722,229 @@ mod iced {
     }
 }
 
+#[cfg(feature = "image")]
+mod image;
+
+#[cfg(feature = "nu-ansi-term")]
+mod nu_ansi_term {
+    use crate::{AnsiColor, Color};
+
+    impl From<Color> for nu_ansi_term::Color {
+        fn from(value: Color) -> Self {
+            Self::Rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<AnsiColor> for nu_ansi_term::Color {
+        fn from(value: AnsiColor) -> Self {
+            Self::Rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<Color> for nu_ansi_term::Style {
+        fn from(value: Color) -> Self {
+            nu_ansi_term::Color::from(value).into()
+        }
+    }
+
+    impl From<AnsiColor> for nu_ansi_term::Style {
+        fn from(value: AnsiColor) -> Self {
+            nu_ansi_term::Color::from(value).into()
+        }
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+mod owo_colors {
+    use owo_colors::{BgDynColorDisplay, FgDynColorDisplay, OwoColorize, Rgb};
+
+    use crate::{AnsiColor, Color};
+
+    // `owo_colors::DynColor` is sealed, so colors can't be passed to
+    // `OwoColorize::color` directly; these mirror it instead.
+    impl Color {
+        /// Paints the given input with this foreground color à la
+        /// [owo-colors](https://docs.rs/owo-colors/latest/owo_colors/)'
+        /// [`OwoColorize::color`].
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// let painted = catppuccin::PALETTE.mocha.colors.green.owo_color(&"ok");
+        /// assert_eq!(painted.to_string(), "\x1b[38;2;166;227;161mok\x1b[39m");
+        /// ```
+        #[must_use]
+        pub fn owo_color<'a, T>(&self, input: &'a T) -> FgDynColorDisplay<'a, Rgb, T> {
+            input.color(Rgb::from(*self))
+        }
+
+        /// Paints the given input with this background color à la
+        /// [owo-colors](https://docs.rs/owo-colors/latest/owo_colors/)'
+        /// [`OwoColorize::on_color`].
+        #[must_use]
+        pub fn owo_on_color<'a, T>(&self, input: &'a T) -> BgDynColorDisplay<'a, Rgb, T> {
+            input.on_color(Rgb::from(*self))
+        }
+    }
+
+    impl AnsiColor {
+        /// Paints the given input with this foreground color à la
+        /// [owo-colors](https://docs.rs/owo-colors/latest/owo_colors/)'
+        /// [`OwoColorize::color`].
+        #[must_use]
+        pub fn owo_color<'a, T>(&self, input: &'a T) -> FgDynColorDisplay<'a, Rgb, T> {
+            input.color(Rgb::from(*self))
+        }
+
+        /// Paints the given input with this background color à la
+        /// [owo-colors](https://docs.rs/owo-colors/latest/owo_colors/)'
+        /// [`OwoColorize::on_color`].
+        #[must_use]
+        pub fn owo_on_color<'a, T>(&self, input: &'a T) -> BgDynColorDisplay<'a, Rgb, T> {
+            input.on_color(Rgb::from(*self))
+        }
+    }
+
+    impl From<Color> for owo_colors::Rgb {
+        fn from(value: Color) -> Self {
+            Self(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<AnsiColor> for owo_colors::Rgb {
+        fn from(value: AnsiColor) -> Self {
+            Self(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<Color> for owo_colors::DynColors {
+        fn from(value: Color) -> Self {
+            Self::Rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<AnsiColor> for owo_colors::DynColors {
+        fn from(value: AnsiColor) -> Self {
+            Self::Rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+}
+
+#[cfg(feature = "palette")]
+mod palette {
+    use crate::{AnsiColor, Color, Hsl, Rgb};
+    use palette::{FromColor, Srgb};
+
+    impl From<Rgb> for Srgb<u8> {
+        fn from(value: Rgb) -> Self {
+            Self::new(value.r, value.g, value.b)
+        }
+    }
+
+    impl From<Rgb> for Srgb<f32> {
+        fn from(value: Rgb) -> Self {
+            Srgb::<u8>::from(value).into_format()
+        }
+    }
+
+    impl From<Rgb> for palette::LinSrgb {
+        fn from(value: Rgb) -> Self {
+            Srgb::<f32>::from(value).into_linear()
+        }
+    }
+
+    impl From<Rgb> for palette::Oklch {
+        fn from(value: Rgb) -> Self {
+            Self::from_color(Srgb::<f32>::from(value))
+        }
+    }
+
+    impl From<Rgb> for palette::Lab {
+        fn from(value: Rgb) -> Self {
+            Self::from_color(Srgb::<f32>::from(value))
+        }
+    }
+
+    impl From<Hsl> for palette::Hsl {
+        fn from(value: Hsl) -> Self {
+            #[allow(clippy::cast_possible_truncation)]
+            Self::new(value.h as f32, value.s as f32, value.l as f32)
+        }
+    }
+
+    impl From<Color> for Srgb<u8> {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<Color> for Srgb<f32> {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<Color> for palette::LinSrgb {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<Color> for palette::Hsl {
+        fn from(value: Color) -> Self {
+            value.hsl.into()
+        }
+    }
+
+    impl From<Color> for palette::Oklch {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<Color> for palette::Lab {
+        fn from(value: Color) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for Srgb<u8> {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for Srgb<f32> {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for palette::LinSrgb {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for palette::Hsl {
+        fn from(value: AnsiColor) -> Self {
+            value.hsl.into()
+        }
+    }
+
+    impl From<AnsiColor> for palette::Oklch {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+
+    impl From<AnsiColor> for palette::Lab {
+        fn from(value: AnsiColor) -> Self {
+            value.rgb.into()
+        }
+    }
+}
+
 #[cfg(feature = "ratatui")]
 mod ratatui {
     use crate::{AnsiColor, Color};
@@ -707,3 +1961,32 @@ mod ratatui {
         }
     }
 }
+
+#[cfg(feature = "yansi")]
+mod yansi {
+    use crate::{AnsiColor, Color};
+
+    impl From<Color> for yansi::Color {
+        fn from(value: Color) -> Self {
+            Self::Rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<AnsiColor> for yansi::Color {
+        fn from(value: AnsiColor) -> Self {
+            Self::Rgb(value.rgb.r, value.rgb.g, value.rgb.b)
+        }
+    }
+
+    impl From<Color> for yansi::Style {
+        fn from(value: Color) -> Self {
+            yansi::Color::from(value).into()
+        }
+    }
+
+    impl From<AnsiColor> for yansi::Style {
+        fn from(value: AnsiColor) -> Self {
+            yansi::Color::from(value).into()
+        }
+    }
+}
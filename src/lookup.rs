@@ -0,0 +1,377 @@
+//! Runtime lookup of colors by dotted path, such as `mocha.ansi.bright_red`.
+use core::{fmt, str::FromStr};
+
+use crate::{
+    AnsiColor, AnsiColorName, AnsiColorPairName, Color, ColorName, FlavorName, Hex, Hsl, Palette,
+    Rgb,
+};
+
+/// A reference to a single color in the palette, parsed from a dotted path.
+///
+/// The following paths are accepted, where each segment is an identifier as
+/// returned by [`FlavorName::identifier`], [`ColorName::identifier`],
+/// [`AnsiColorName::identifier`] or [`AnsiColorPairName::identifier`]:
+///
+/// - `<flavor>.<color>`, e.g. `mocha.mauve`
+/// - `<flavor>.ansi.<ansi color>`, e.g. `mocha.ansi.bright_red`
+/// - `<flavor>.ansi_pairs.<ansi color pair>.<normal|bright>`, e.g.
+///   `mocha.ansi_pairs.red.bright`
+///
+/// Formatting a `ColorRef` with [`Display`](fmt::Display) yields the same path.
+/// For paths known at compile time, see the [`color!`](crate::color) macro.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{AnsiColorName, ColorRef, FlavorName, PALETTE};
+///
+/// let color_ref: ColorRef = "mocha.ansi.bright_red".parse().unwrap();
+/// assert_eq!(
+///     color_ref,
+///     ColorRef::Ansi {
+///         flavor: FlavorName::Mocha,
+///         name: AnsiColorName::BrightRed,
+///     }
+/// );
+/// assert_eq!(color_ref.resolve(&PALETTE).hex(), PALETTE.mocha.ansi_colors.bright_red.hex);
+/// assert_eq!(color_ref.to_string(), "mocha.ansi.bright_red");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorRef {
+    /// One of a flavor's colors.
+    Color {
+        /// The flavor the color belongs to.
+        flavor: FlavorName,
+        /// The name of the color.
+        name: ColorName,
+    },
+    /// One of a flavor's ANSI colors.
+    Ansi {
+        /// The flavor the ANSI color belongs to.
+        flavor: FlavorName,
+        /// The name of the ANSI color.
+        name: AnsiColorName,
+    },
+    /// The normal or bright member of one of a flavor's ANSI color pairs.
+    AnsiPair {
+        /// The flavor the ANSI color pair belongs to.
+        flavor: FlavorName,
+        /// The name of the ANSI color pair.
+        pair: AnsiColorPairName,
+        /// Whether the bright member of the pair is referenced, rather than the
+        /// normal member.
+        bright: bool,
+    },
+}
+
+/// A color obtained by resolving a [`ColorRef`] against a [`Palette`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResolvedColor<'a> {
+    /// One of a flavor's colors.
+    Color(&'a Color),
+    /// One of a flavor's ANSI colors.
+    Ansi(&'a AnsiColor),
+}
+
+/// The segments of a [`ColorRef`] path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorRefSegment {
+    /// A flavor identifier.
+    Flavor,
+    /// A color identifier, or one of `ansi` and `ansi_pairs`.
+    Color,
+    /// An ANSI color identifier.
+    AnsiColor,
+    /// An ANSI color pair identifier.
+    AnsiColorPair,
+    /// One of `normal` and `bright`.
+    AnsiColorPairMember,
+    /// The end of the path.
+    End,
+}
+
+/// Error type for parsing a [`ColorRef`] from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseColorRefError {
+    /// The zero-based index of the segment that failed to parse.
+    pub index: usize,
+    /// What the segment was expected to be.
+    pub expected: ColorRefSegment,
+    /// Whether the segment was missing entirely, rather than invalid.
+    pub missing: bool,
+}
+
+impl core::error::Error for ParseColorRefError {}
+impl fmt::Display for ParseColorRefError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let segment = self.index + 1;
+        let expected = match self.expected {
+            ColorRefSegment::Flavor => "flavor identifier",
+            ColorRefSegment::Color => "color identifier",
+            ColorRefSegment::AnsiColor => "ANSI color identifier",
+            ColorRefSegment::AnsiColorPair => "ANSI color pair identifier",
+            ColorRefSegment::AnsiColorPairMember => "ANSI color pair member",
+            ColorRefSegment::End => {
+                return write!(f, "unexpected segment {segment} in color path");
+            }
+        };
+        let problem = if self.missing { "missing" } else { "invalid" };
+        write!(
+            f,
+            "{problem} {expected} in segment {segment} of color path, expected one of: "
+        )?;
+
+        match self.expected {
+            ColorRefSegment::Flavor => {
+                write_options(f, FlavorName::ALL.iter().map(FlavorName::identifier))
+            }
+            ColorRefSegment::Color => write_options(
+                f,
+                ColorName::ALL
+                    .iter()
+                    .map(ColorName::identifier)
+                    .chain(["ansi", "ansi_pairs"]),
+            ),
+            ColorRefSegment::AnsiColor => {
+                write_options(f, AnsiColorName::ALL.iter().map(AnsiColorName::identifier))
+            }
+            ColorRefSegment::AnsiColorPair => write_options(
+                f,
+                AnsiColorPairName::ALL
+                    .iter()
+                    .map(AnsiColorPairName::identifier),
+            ),
+            ColorRefSegment::AnsiColorPairMember => {
+                write_options(f, ["normal", "bright"].into_iter())
+            }
+            ColorRefSegment::End => Ok(()),
+        }
+    }
+}
+
+fn write_options<'a>(
+    f: &mut fmt::Formatter,
+    mut options: impl Iterator<Item = &'a str>,
+) -> fmt::Result {
+    if let Some(first) = options.next() {
+        write!(f, "{first}")?;
+    }
+    for option in options {
+        write!(f, ", {option}")?;
+    }
+    Ok(())
+}
+
+impl FromStr for ColorRef {
+    type Err = ParseColorRefError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = s.split('.');
+        let mut index = 0;
+        let mut next = |expected| {
+            let segment = segments.next();
+            let error = ParseColorRefError {
+                index,
+                expected,
+                missing: segment.is_none(),
+            };
+            index += 1;
+            segment.ok_or(error).map(|segment| (segment, error))
+        };
+
+        let (segment, error) = next(ColorRefSegment::Flavor)?;
+        let flavor = segment.parse().map_err(|_| error)?;
+
+        let (segment, error) = next(ColorRefSegment::Color)?;
+        let color_ref = match segment {
+            "ansi" => {
+                let (segment, error) = next(ColorRefSegment::AnsiColor)?;
+                let name = segment.parse().map_err(|_| error)?;
+                Self::Ansi { flavor, name }
+            }
+            "ansi_pairs" => {
+                let (segment, error) = next(ColorRefSegment::AnsiColorPair)?;
+                let pair = segment.parse().map_err(|_| error)?;
+                let (segment, error) = next(ColorRefSegment::AnsiColorPairMember)?;
+                let bright = match segment {
+                    "normal" => false,
+                    "bright" => true,
+                    _ => return Err(error),
+                };
+                Self::AnsiPair {
+                    flavor,
+                    pair,
+                    bright,
+                }
+            }
+            _ => {
+                let name = segment.parse().map_err(|_| error)?;
+                Self::Color { flavor, name }
+            }
+        };
+
+        match next(ColorRefSegment::End) {
+            Ok((_, error)) => Err(error),
+            Err(_) => Ok(color_ref),
+        }
+    }
+}
+
+impl fmt::Display for ColorRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Color { flavor, name } => {
+                write!(f, "{}.{}", flavor.identifier(), name.identifier())
+            }
+            Self::Ansi { flavor, name } => {
+                write!(f, "{}.ansi.{}", flavor.identifier(), name.identifier())
+            }
+            Self::AnsiPair {
+                flavor,
+                pair,
+                bright,
+            } => {
+                let member = if *bright { "bright" } else { "normal" };
+                write!(
+                    f,
+                    "{}.ansi_pairs.{}.{member}",
+                    flavor.identifier(),
+                    pair.identifier()
+                )
+            }
+        }
+    }
+}
+
+impl ColorRef {
+    /// Get the flavor the referenced color belongs to.
+    #[must_use]
+    pub const fn flavor(&self) -> FlavorName {
+        match self {
+            Self::Color { flavor, .. }
+            | Self::Ansi { flavor, .. }
+            | Self::AnsiPair { flavor, .. } => *flavor,
+        }
+    }
+
+    /// Resolve the reference to a color in the given palette.
+    #[must_use]
+    pub const fn resolve(self, palette: &Palette) -> ResolvedColor<'_> {
+        let flavor = palette.get_flavor(self.flavor());
+        match self {
+            Self::Color { name, .. } => ResolvedColor::Color(flavor.get_color(name)),
+            Self::Ansi { name, .. } => ResolvedColor::Ansi(flavor.ansi_colors.get_ansi_color(name)),
+            Self::AnsiPair { pair, bright, .. } => {
+                let pairs = flavor.ansi_colors.all_pairs();
+                let pair = pairs.get_ansi_color_pair(pair);
+                let member = if bright { pair.bright } else { pair.normal };
+                ResolvedColor::Ansi(flavor.ansi_colors.get_ansi_color(member.name))
+            }
+        }
+    }
+}
+
+impl ResolvedColor<'_> {
+    /// The color represented as a six-digit hex string with a leading hash (#).
+    #[must_use]
+    pub const fn hex(&self) -> Hex {
+        match self {
+            Self::Color(color) => color.hex,
+            Self::Ansi(color) => color.hex,
+        }
+    }
+
+    /// The color represented as individual red, green, and blue channels.
+    #[must_use]
+    pub const fn rgb(&self) -> Rgb {
+        match self {
+            Self::Color(color) => color.rgb,
+            Self::Ansi(color) => color.rgb,
+        }
+    }
+
+    /// The color represented as individual hue, saturation, and lightness channels.
+    #[must_use]
+    pub const fn hsl(&self) -> Hsl {
+        match self {
+            Self::Color(color) => color.hsl,
+            Self::Ansi(color) => color.hsl,
+        }
+    }
+}
+
+impl Palette {
+    /// Look up a color by dotted path, as described on [`ColorRef`].
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{ColorRefSegment, ResolvedColor, PALETTE};
+    ///
+    /// let ResolvedColor::Ansi(bright_red) = PALETTE.lookup("mocha.ansi.bright_red").unwrap() else {
+    ///     panic!("expected an ANSI color");
+    /// };
+    /// assert_eq!(bright_red.code, 9);
+    ///
+    /// let error = PALETTE.lookup("mocha.ansi.brigth_red").unwrap_err();
+    /// assert_eq!(error.index, 2);
+    /// assert_eq!(error.expected, ColorRefSegment::AnsiColor);
+    /// assert!(error.to_string().starts_with(
+    ///     "invalid ANSI color identifier in segment 3 of color path, expected one of: black, red,"
+    /// ));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not a valid [`ColorRef`].
+    pub fn lookup(&self, path: &str) -> Result<ResolvedColor<'_>, ParseColorRefError> {
+        Ok(path.parse::<ColorRef>()?.resolve(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod _serde {
+    use core::fmt;
+
+    use serde::{
+        de::{self, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::ColorRef;
+
+    impl Serialize for ColorRef {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct ColorRefVisitor;
+
+    impl Visitor<'_> for ColorRefVisitor {
+        type Value = ColorRef;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a dotted color path such as \"mocha.mauve\"")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ColorRef {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(ColorRefVisitor)
+        }
+    }
+}
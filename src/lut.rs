@@ -0,0 +1,137 @@
+//! 3D lookup tables mapping the sRGB cube onto the colors of a flavor.
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use crate::{Dither, Recolor};
+
+/// How each entry of a lookup table is mapped onto a flavor's colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LutMapping {
+    /// Map each entry to the nearest color, as with [`Recolor::quantize`].
+    Nearest,
+    /// Smoothly blend each entry toward the colors, as with [`Recolor::tint`]
+    /// at full strength.
+    Smooth,
+}
+
+/// A 3D color lookup table.
+/// Obtained via [`Recolor::lut`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lut {
+    /// The number of entries along each axis of the cube.
+    pub size: usize,
+    /// The output colors as packed 8-bit RGB, with red varying fastest,
+    /// then green, then blue.
+    pub pixels: Vec<u8>,
+}
+
+/// A Hald CLUT image; a 3D lookup table laid out as a square image.
+/// Obtained via [`Recolor::hald_clut`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HaldClut {
+    /// The level of the CLUT. The cube has `level²` entries along each axis, and
+    /// the image is `level³` pixels wide and high.
+    pub level: usize,
+    /// The image as packed 8-bit RGB pixels in row-major order.
+    pub pixels: Vec<u8>,
+}
+
+impl Recolor<'_> {
+    /// Generate a lookup table with `size` entries along each axis, mapping the
+    /// sRGB cube onto the selected colors. [`Recolor::dither`] is ignored.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{LutMapping, Recolor, PALETTE};
+    ///
+    /// let lut = Recolor::new(&PALETTE.latte).lut(17, LutMapping::Nearest);
+    /// let cube = lut.to_cube("Catppuccin Latte");
+    /// assert!(cube.starts_with("TITLE \"Catppuccin Latte\"\nLUT_3D_SIZE 17\n"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is less than 2 or greater than 256.
+    #[must_use]
+    pub fn lut(&self, size: usize, mapping: LutMapping) -> Lut {
+        assert!(
+            (2..=256).contains(&size),
+            "LUT size must be between 2 and 256"
+        );
+        let mut pixels = Vec::with_capacity(size * size * size * 3);
+        #[allow(clippy::cast_possible_truncation)]
+        let sample = |index: usize| ((index * 255 * 2 + size - 1) / ((size - 1) * 2)) as u8;
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    pixels.extend([sample(r), sample(g), sample(b)]);
+                }
+            }
+        }
+
+        match mapping {
+            LutMapping::Nearest => Recolor {
+                dither: Dither::None,
+                ..*self
+            }
+            .quantize(&mut pixels, size),
+            LutMapping::Smooth => self.tint(&mut pixels, 1.0),
+        }
+        Lut { size, pixels }
+    }
+
+    /// Generate a Hald CLUT image of the given level, mapping the sRGB cube onto
+    /// the selected colors. [`Recolor::dither`] is ignored.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{LutMapping, Recolor, PALETTE};
+    ///
+    /// let clut = Recolor::new(&PALETTE.mocha).hald_clut(8, LutMapping::Smooth);
+    /// assert_eq!(clut.width(), 512);
+    /// assert_eq!(clut.pixels.len(), 512 * 512 * 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` is less than 2 or greater than 16.
+    #[must_use]
+    pub fn hald_clut(&self, level: usize, mapping: LutMapping) -> HaldClut {
+        assert!(
+            (2..=16).contains(&level),
+            "Hald CLUT level must be between 2 and 16"
+        );
+        // a Hald CLUT is a cube of size level² in the same order as a .cube LUT,
+        // wrapped into rows of level³ pixels
+        let Lut { pixels, .. } = self.lut(level * level, mapping);
+        HaldClut { level, pixels }
+    }
+}
+
+impl Lut {
+    /// Serialize the lookup table in the Adobe / `DaVinci` Resolve `.cube` format.
+    #[must_use]
+    pub fn to_cube(&self, title: &str) -> String {
+        let mut cube = String::new();
+        // writing to a String cannot fail
+        let _ = writeln!(cube, "TITLE \"{title}\"");
+        let _ = writeln!(cube, "LUT_3D_SIZE {}", self.size);
+        let _ = writeln!(cube, "DOMAIN_MIN 0.0 0.0 0.0");
+        let _ = writeln!(cube, "DOMAIN_MAX 1.0 1.0 1.0");
+        for pixel in self.pixels.chunks_exact(3) {
+            let [r, g, b] = [pixel[0], pixel[1], pixel[2]].map(|c| f32::from(c) / 255.0);
+            let _ = writeln!(cube, "{r:.6} {g:.6} {b:.6}");
+        }
+        cube
+    }
+}
+
+impl HaldClut {
+    /// The width (and height) of the image in pixels.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.level * self.level * self.level
+    }
+}
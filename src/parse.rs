@@ -0,0 +1,173 @@
+//! Lenient parsing of flavor and color names, with suggestions for typos.
+use crate::{AccentName, AnsiColorName, AnsiColorPairName, ColorName};
+
+#[cfg(feature = "serde")]
+pub use self::_serde::{DidYouMean, KeySeed};
+
+/// The longest name that suggestions are offered for.
+const MAX_SUGGESTION_LEN: usize = 32;
+
+/// Common aliases for color names, in addition to their identifiers and display names.
+pub const COLOR_NAME_ALIASES: &[(&str, ColorName)] = &[("lav", ColorName::Lavender)];
+
+/// Common aliases for accent names; the accent subset of [`COLOR_NAME_ALIASES`].
+pub const ACCENT_NAME_ALIASES: &[(&str, AccentName)] = &[("lav", AccentName::Lavender)];
+
+/// Common aliases for ANSI color names, in addition to their identifiers and
+/// display names. Some terminals call magenta "purple".
+pub const ANSI_COLOR_NAME_ALIASES: &[(&str, AnsiColorName)] = &[
+    ("purple", AnsiColorName::Magenta),
+    ("bright_purple", AnsiColorName::BrightMagenta),
+];
+
+/// Common aliases for ANSI color pair names, in addition to their identifiers and
+/// display names.
+pub const ANSI_COLOR_PAIR_NAME_ALIASES: &[(&str, AnsiColorPairName)] =
+    &[("purple", AnsiColorPairName::Magenta)];
+
+/// Parse a name leniently against its identifiers and aliases.
+///
+/// Matching ignores case, whitespace, underscores, hyphens and the accent in
+/// "Frappé", so display names such as `Bright Red` and variants such as
+/// `surface-0` are accepted. On failure, returns the identifier most similar to
+/// `s`, if any is similar enough to be a likely typo.
+pub fn parse_name<T: Copy + PartialEq>(
+    s: &str,
+    identifiers: &[(&'static str, T)],
+    aliases: &[(&'static str, T)],
+) -> Result<T, Option<&'static str>> {
+    let candidates = identifiers.iter().chain(aliases);
+    if let Some((_, name)) = candidates
+        .clone()
+        .find(|(candidate, _)| normalize(s).eq(normalize(candidate)))
+    {
+        return Ok(*name);
+    }
+
+    let suggestion = candidates
+        .filter_map(|(candidate, name)| {
+            let distance = distance(s, candidate)?;
+            let threshold = (normalize(candidate).count() / 3).max(1);
+            (distance <= threshold).then_some((distance, name))
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .and_then(|(_, name)| {
+            identifiers
+                .iter()
+                .find(|(_, identifier)| identifier == name)
+                .map(|(identifier, _)| *identifier)
+        });
+    Err(suggestion)
+}
+
+fn normalize(s: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    s.chars()
+        .filter(|c| !(c.is_whitespace() || *c == '_' || *c == '-'))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'é' { 'e' } else { c })
+}
+
+/// The optimal string alignment distance between the normalized forms of `a`
+/// and `b`; the number of insertions, deletions, substitutions and transpositions
+/// of adjacent characters needed to turn one into the other.
+///
+/// Returns `None` if `b` is longer than [`MAX_SUGGESTION_LEN`].
+fn distance(a: &str, b: &str) -> Option<usize> {
+    let mut target = ['\0'; MAX_SUGGESTION_LEN];
+    let mut len = 0;
+    for c in normalize(b) {
+        *target.get_mut(len)? = c;
+        len += 1;
+    }
+    let target = &target[..len];
+
+    let mut previous_row = [0; MAX_SUGGESTION_LEN + 1];
+    let mut row: [usize; MAX_SUGGESTION_LEN + 1] = core::array::from_fn(|i| i);
+    let mut previous_char = None;
+    for (i, a) in normalize(a).enumerate() {
+        let before_previous_row = previous_row;
+        previous_row = row;
+        row[0] = i + 1;
+        for (j, b) in target.iter().enumerate() {
+            let cost = usize::from(a != *b);
+            row[j + 1] = (previous_row[j + 1] + 1)
+                .min(row[j] + 1)
+                .min(previous_row[j] + cost);
+            if j > 0 && previous_char == Some(*b) && a == target[j - 1] {
+                row[j + 1] = row[j + 1].min(before_previous_row[j - 1] + 1);
+            }
+        }
+        previous_char = Some(a);
+    }
+    Some(row[len])
+}
+
+#[cfg(feature = "serde")]
+mod _serde {
+    use core::fmt;
+
+    use serde::{
+        de::{self, DeserializeSeed, Visitor},
+        Deserializer,
+    };
+
+    /// Formats an optional suggestion as returned by [`parse_name`](super::parse_name),
+    /// to be appended to an error message.
+    pub struct DidYouMean(pub Option<&'static str>);
+
+    impl fmt::Display for DidYouMean {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            self.0.map_or(Ok(()), |suggestion| {
+                write!(f, " (did you mean `{suggestion}`?)")
+            })
+        }
+    }
+
+    /// Deserializes a string that must exactly match one of `identifiers`,
+    /// yielding its index. Errors suggest the most similar identifier.
+    pub struct KeySeed<const N: usize> {
+        /// What the identifiers name, such as "color", for error messages.
+        pub kind: &'static str,
+        /// The accepted identifiers.
+        pub identifiers: [&'static str; N],
+    }
+
+    impl<'de, const N: usize> DeserializeSeed<'de> for KeySeed<N> {
+        type Value = usize;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<const N: usize> Visitor<'_> for KeySeed<N> {
+        type Value = usize;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a {} identifier", self.kind)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            // identifiers must match exactly, but lenient parsing finds the suggestion
+            if let Some(index) = self.identifiers.iter().position(|&key| key == v) {
+                return Ok(index);
+            }
+            let candidates: [_; N] = core::array::from_fn(|i| (self.identifiers[i], i));
+            let suggestion = match super::parse_name(v, &candidates, &[]) {
+                Ok(index) => Some(self.identifiers[index]),
+                Err(suggestion) => suggestion,
+            };
+            Err(E::custom(format_args!(
+                "unknown {} `{v}`{}",
+                self.kind,
+                DidYouMean(suggestion)
+            )))
+        }
+    }
+}
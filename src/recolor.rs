@@ -0,0 +1,274 @@
+//! Recoloring of arbitrary RGB pixel buffers to the colors of a flavor.
+use crate::{ColorName, Flavor, Rgb};
+
+/// 4x4 Bayer matrix used for ordered dithering.
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// The maximum offset applied to each channel by ordered dithering.
+const ORDERED_SPREAD: f32 = 32.0;
+
+/// Recolors RGB pixel buffers to the colors of a [`Flavor`].
+///
+/// Buffers are tightly packed 8-bit RGB, i.e. three bytes per pixel in row-major order,
+/// as produced by most image decoders.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{Dither, Recolor, PALETTE};
+///
+/// // a purple pixel and a near-black pixel
+/// let mut pixels = [200, 160, 250, 16, 16, 24];
+/// let recolor = Recolor {
+///     dither: Dither::None,
+///     ..Recolor::new(&PALETTE.mocha)
+/// };
+/// recolor.quantize(&mut pixels, 2);
+///
+/// let (mauve, crust) = (PALETTE.mocha.colors.mauve.rgb, PALETTE.mocha.colors.crust.rgb);
+/// assert_eq!(pixels, [mauve.r, mauve.g, mauve.b, crust.r, crust.g, crust.b]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Recolor<'a> {
+    /// The flavor to take colors from.
+    pub flavor: &'a Flavor,
+    /// Which of the flavor's colors pixels may be mapped to.
+    pub colors: ColorSelection<'a>,
+    /// The dithering applied by [`Recolor::quantize`].
+    pub dither: Dither,
+    /// How the distance between two colors is measured.
+    pub metric: Metric,
+}
+
+/// The subset of a flavor's colors used by [`Recolor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorSelection<'a> {
+    /// All 26 colors in the flavor.
+    All,
+    /// Only the 14 accent colors.
+    Accents,
+    /// Only the given colors.
+    Only(&'a [ColorName]),
+}
+
+/// Dithering algorithms for [`Recolor::quantize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dither {
+    /// Map every pixel to its nearest color.
+    None,
+    /// Floyd–Steinberg error diffusion.
+    FloydSteinberg,
+    /// Ordered dithering with a 4x4 Bayer matrix.
+    Ordered,
+}
+
+/// Color distance metrics for [`Recolor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// Euclidean distance between RGB channels.
+    Euclidean,
+    /// The "redmean" weighted RGB distance, a cheap approximation of perceived
+    /// color difference.
+    Redmean,
+}
+
+impl<'a> Recolor<'a> {
+    /// Create a recolorer for the given flavor using all of its colors,
+    /// Floyd–Steinberg dithering and the [`Metric::Redmean`] metric.
+    #[must_use]
+    pub const fn new(flavor: &'a Flavor) -> Self {
+        Self {
+            flavor,
+            colors: ColorSelection::All,
+            dither: Dither::FloydSteinberg,
+            metric: Metric::Redmean,
+        }
+    }
+
+    /// Map every pixel in `pixels` to the nearest selected color, applying
+    /// [`Recolor::dither`]. `width` is the number of pixels per row.
+    ///
+    /// If the selection is empty, the pixels are left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero or the buffer is not made up of whole rows of
+    /// `width` RGB pixels.
+    pub fn quantize(&self, pixels: &mut [u8], width: usize) {
+        assert!(width > 0, "width must be non-zero");
+        assert_eq!(
+            pixels.len() % (width * 3),
+            0,
+            "buffer must contain whole rows of RGB pixels"
+        );
+        let (candidates, count) = self.candidates();
+        let candidates = &candidates[..count];
+        if candidates.is_empty() {
+            return;
+        }
+
+        let height = pixels.len() / (width * 3);
+        for y in 0..height {
+            for x in 0..width {
+                let offset = (y * width + x) * 3;
+                let pixel = rgb_at(pixels, offset);
+                let target = match self.dither {
+                    Dither::Ordered => {
+                        let threshold = f32::from(BAYER[y % 4][x % 4]) / 16.0 - 0.5;
+                        let shift = threshold * ORDERED_SPREAD;
+                        Rgb {
+                            r: clamp(f32::from(pixel.r) + shift),
+                            g: clamp(f32::from(pixel.g) + shift),
+                            b: clamp(f32::from(pixel.b) + shift),
+                        }
+                    }
+                    Dither::None | Dither::FloydSteinberg => pixel,
+                };
+                let nearest = self.nearest(candidates, target);
+                set_rgb(pixels, offset, nearest);
+
+                if self.dither == Dither::FloydSteinberg {
+                    let error = [
+                        i16::from(pixel.r) - i16::from(nearest.r),
+                        i16::from(pixel.g) - i16::from(nearest.g),
+                        i16::from(pixel.b) - i16::from(nearest.b),
+                    ];
+                    let mut diffuse = |dx: isize, dy: usize, weight: i16| {
+                        let Some(nx) = x.checked_add_signed(dx).filter(|nx| *nx < width) else {
+                            return;
+                        };
+                        let ny = y + dy;
+                        if ny >= height {
+                            return;
+                        }
+                        let offset = (ny * width + nx) * 3;
+                        for (channel, error) in pixels[offset..offset + 3].iter_mut().zip(error) {
+                            let value = i16::from(*channel) + error * weight / 16;
+                            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                            let value = value.clamp(0, 255) as u8;
+                            *channel = value;
+                        }
+                    };
+                    diffuse(1, 0, 7);
+                    diffuse(-1, 1, 3);
+                    diffuse(0, 1, 5);
+                    diffuse(1, 1, 1);
+                }
+            }
+        }
+    }
+
+    /// Blend every pixel in `pixels` toward the selected colors, instead of
+    /// quantizing to them.
+    ///
+    /// Each pixel is moved toward a weighted average of the selected colors, where
+    /// nearer colors carry much more weight, giving a smooth result without
+    /// banding. `strength` ranges from `0.0` (unchanged) to `1.0` (fully tinted).
+    /// [`Recolor::dither`] is ignored, and pixels are processed independently, so
+    /// no width is needed.
+    ///
+    /// If the selection is empty, the pixels are left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not made up of whole RGB pixels.
+    pub fn tint(&self, pixels: &mut [u8], strength: f32) {
+        assert_eq!(pixels.len() % 3, 0, "buffer must contain whole RGB pixels");
+        let (candidates, count) = self.candidates();
+        let candidates = &candidates[..count];
+        if candidates.is_empty() {
+            return;
+        }
+        let strength = strength.clamp(0.0, 1.0);
+
+        for pixel in pixels.chunks_exact_mut(3) {
+            let rgb = rgb_at(pixel, 0);
+            let mut total = 0.0;
+            let mut sum = [0.0_f32; 3];
+            let mut exact = None;
+            for candidate in candidates {
+                let distance = self.distance(rgb, *candidate);
+                if distance == 0 {
+                    exact = Some(*candidate);
+                    break;
+                }
+                #[allow(clippy::cast_precision_loss)]
+                let distance = distance as f32;
+                let weight = 1.0 / (distance * distance);
+                total += weight;
+                sum[0] += weight * f32::from(candidate.r);
+                sum[1] += weight * f32::from(candidate.g);
+                sum[2] += weight * f32::from(candidate.b);
+            }
+            let target = exact.map_or_else(
+                || sum.map(|channel| channel / total),
+                |exact| [exact.r, exact.g, exact.b].map(f32::from),
+            );
+            for (channel, target) in pixel.iter_mut().zip(target) {
+                let value = f32::from(*channel);
+                *channel = clamp(value + (target - value) * strength);
+            }
+        }
+    }
+
+    /// Get the RGB values of the selected colors, and how many there are.
+    fn candidates(&self) -> ([Rgb; 26], usize) {
+        let mut candidates = [Rgb { r: 0, g: 0, b: 0 }; 26];
+        let mut count = 0;
+        for color in self.flavor {
+            let selected = match self.colors {
+                ColorSelection::All => true,
+                ColorSelection::Accents => color.accent,
+                ColorSelection::Only(names) => names.contains(&color.name),
+            };
+            if selected {
+                candidates[count] = color.rgb;
+                count += 1;
+            }
+        }
+        (candidates, count)
+    }
+
+    fn nearest(&self, candidates: &[Rgb], rgb: Rgb) -> Rgb {
+        candidates
+            .iter()
+            .copied()
+            .min_by_key(|candidate| self.distance(rgb, *candidate))
+            .unwrap_or(rgb)
+    }
+
+    /// A squared distance between two colors according to [`Recolor::metric`].
+    fn distance(&self, a: Rgb, b: Rgb) -> u32 {
+        let dr = i32::from(a.r) - i32::from(b.r);
+        let dg = i32::from(a.g) - i32::from(b.g);
+        let db = i32::from(a.b) - i32::from(b.b);
+        let distance = match self.metric {
+            Metric::Euclidean => dr * dr + dg * dg + db * db,
+            Metric::Redmean => {
+                let rmean = i32::midpoint(i32::from(a.r), i32::from(b.r));
+                (((512 + rmean) * dr * dr) >> 8) + 4 * dg * dg + (((767 - rmean) * db * db) >> 8)
+            }
+        };
+        distance.unsigned_abs()
+    }
+}
+
+const fn rgb_at(pixels: &[u8], offset: usize) -> Rgb {
+    Rgb {
+        r: pixels[offset],
+        g: pixels[offset + 1],
+        b: pixels[offset + 2],
+    }
+}
+
+const fn set_rgb(pixels: &mut [u8], offset: usize, rgb: Rgb) {
+    pixels[offset] = rgb.r;
+    pixels[offset + 1] = rgb.g;
+    pixels[offset + 2] = rgb.b;
+}
+
+/// Round and clamp a channel value to the `u8` range.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn clamp(value: f32) -> u8 {
+    (value.clamp(0.0, 255.0) + 0.5) as u8
+}
@@ -0,0 +1,494 @@
+//! The schema of the upstream `palette.json`, as published by
+//! [catppuccin/palette](https://github.com/catppuccin/palette).
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use crate::{Palette, PALETTE_VERSION};
+
+/// The version of a palette spec, such as `1.7.1`.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{PaletteVersion, PALETTE_VERSION};
+///
+/// let version: PaletteVersion = "1.7.1".parse().unwrap();
+/// assert_eq!(version.to_string(), "1.7.1");
+/// assert!(version <= PALETTE_VERSION);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PaletteVersion {
+    /// The major version.
+    pub major: u32,
+    /// The minor version.
+    pub minor: u32,
+    /// The patch version.
+    pub patch: u32,
+}
+
+impl fmt::Display for PaletteVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Error type for parsing a [`PaletteVersion`] from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParsePaletteVersionError;
+
+impl core::error::Error for ParsePaletteVersionError {}
+impl fmt::Display for ParsePaletteVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid palette version, expected major.minor.patch")
+    }
+}
+
+impl FromStr for PaletteVersion {
+    type Err = ParsePaletteVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('.').map(str::parse);
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => Ok(Self {
+                major,
+                minor,
+                patch,
+            }),
+            _ => Err(ParsePaletteVersionError),
+        }
+    }
+}
+
+/// A palette and the version of the palette spec it follows, serialized in
+/// exactly the schema of the upstream `palette.json`.
+///
+/// Unlike the serde representation of [`Palette`] itself, flavors, colors and
+/// ANSI color pairs are keyed by their identifiers, ANSI colors are grouped in
+/// pairs under `ansiColors`, and the palette version is kept. Files written this
+/// way are accepted by the rest of the Catppuccin tooling, and alternate or
+/// user-edited palette files can be loaded at runtime.
+///
+/// When deserializing, every flavor, color and ANSI color pair must be present.
+/// Names are derived from the keys, and unknown fields are ignored.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{Hex, PaletteFile, PaletteVersion, PALETTE};
+///
+/// let upstream = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/palette.json"));
+/// let file: PaletteFile = serde_json::from_str(upstream)?;
+/// assert_eq!(file, PaletteFile::new(PALETTE));
+///
+/// let mut file = PaletteFile {
+///     version: PaletteVersion { major: 2, minor: 0, patch: 0 },
+///     ..PaletteFile::new(PALETTE)
+/// };
+/// file.palette.mocha.colors.base.hex = Hex::from((0, 0, 0));
+/// let json = serde_json::to_string_pretty(&file)?;
+/// assert!(json.starts_with("{\n  \"version\": \"2.0.0\",\n  \"latte\": {\n    \"name\": \"Latte\","));
+/// assert!(json.contains("\"ansiColors\": {\n      \"black\": {\n        \"name\": \"Black\","));
+/// assert_eq!(serde_json::from_str::<PaletteFile>(&json)?, file);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaletteFile {
+    /// The version of the palette spec.
+    pub version: PaletteVersion,
+    /// The palette.
+    pub palette: Palette,
+}
+
+#[cfg(feature = "serde")]
+impl PaletteFile {
+    /// Create a palette file for the given palette, following the palette spec
+    /// version of [`PALETTE`](crate::PALETTE).
+    #[must_use]
+    pub const fn new(palette: Palette) -> Self {
+        Self {
+            version: PALETTE_VERSION,
+            palette,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod _serde {
+    use core::fmt;
+
+    use serde::{
+        de::{self, MapAccess, Visitor},
+        ser::{SerializeMap, SerializeStruct},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::{PaletteFile, PaletteVersion};
+    use crate::{
+        parse::KeySeed, AnsiColor, AnsiColorName, AnsiColorPair, AnsiColorPairName, Color,
+        ColorName, Flavor, FlavorAnsiColors, FlavorColors, FlavorName, Hex, Hsl, Rgb, PALETTE,
+    };
+
+    impl Serialize for PaletteVersion {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct PaletteVersionVisitor;
+
+    impl Visitor<'_> for PaletteVersionVisitor {
+        type Value = PaletteVersion;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a version string such as \"1.7.1\"")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PaletteVersion {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(PaletteVersionVisitor)
+        }
+    }
+
+    /// Serializes a name as its display name, such as `Frappé` or `Surface 0`.
+    struct DisplayName<T>(T);
+
+    impl<T: fmt::Display> Serialize for DisplayName<T> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(&self.0)
+        }
+    }
+
+    impl Serialize for PaletteFile {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let flavors = self.palette.all_flavors();
+            let mut map = serializer.serialize_map(Some(flavors.len() + 1))?;
+            map.serialize_entry("version", &self.version)?;
+            for flavor in flavors {
+                map.serialize_entry(flavor.identifier(), &FlavorEntry(flavor))?;
+            }
+            map.end()
+        }
+    }
+
+    struct FlavorEntry<'a>(&'a Flavor);
+
+    impl Serialize for FlavorEntry<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut flavor = serializer.serialize_struct("Flavor", 6)?;
+            flavor.serialize_field("name", &DisplayName(self.0.name))?;
+            flavor.serialize_field("emoji", &self.0.emoji)?;
+            flavor.serialize_field("order", &self.0.order)?;
+            flavor.serialize_field("dark", &self.0.dark)?;
+            flavor.serialize_field("colors", &ColorsEntry(&self.0.colors))?;
+            flavor.serialize_field("ansiColors", &AnsiColorsEntry(&self.0.ansi_colors))?;
+            flavor.end()
+        }
+    }
+
+    struct ColorsEntry<'a>(&'a FlavorColors);
+
+    impl Serialize for ColorsEntry<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(
+                self.0
+                    .iter()
+                    .map(|color| (color.identifier(), ColorEntry(color))),
+            )
+        }
+    }
+
+    struct ColorEntry<'a>(&'a Color);
+
+    impl Serialize for ColorEntry<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut color = serializer.serialize_struct("Color", 6)?;
+            color.serialize_field("name", &DisplayName(self.0.name))?;
+            color.serialize_field("order", &self.0.order)?;
+            color.serialize_field("hex", &self.0.hex)?;
+            color.serialize_field("rgb", &self.0.rgb)?;
+            color.serialize_field("hsl", &self.0.hsl)?;
+            color.serialize_field("accent", &self.0.accent)?;
+            color.end()
+        }
+    }
+
+    struct AnsiColorsEntry<'a>(&'a FlavorAnsiColors);
+
+    impl Serialize for AnsiColorsEntry<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let pairs = self.0.all_pairs();
+            serializer.collect_map(
+                pairs
+                    .iter()
+                    .map(|pair| (pair.name.identifier(), AnsiColorPairEntry(pair))),
+            )
+        }
+    }
+
+    struct AnsiColorPairEntry<'a>(&'a AnsiColorPair);
+
+    impl Serialize for AnsiColorPairEntry<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut pair = serializer.serialize_struct("AnsiColorPair", 4)?;
+            pair.serialize_field("name", &DisplayName(self.0.name))?;
+            pair.serialize_field("order", &self.0.order)?;
+            pair.serialize_field("normal", &AnsiColorEntry(&self.0.normal))?;
+            pair.serialize_field("bright", &AnsiColorEntry(&self.0.bright))?;
+            pair.end()
+        }
+    }
+
+    struct AnsiColorEntry<'a>(&'a AnsiColor);
+
+    impl Serialize for AnsiColorEntry<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut color = serializer.serialize_struct("AnsiColor", 5)?;
+            color.serialize_field("name", &DisplayName(self.0.name))?;
+            color.serialize_field("hex", &self.0.hex)?;
+            color.serialize_field("rgb", &self.0.rgb)?;
+            color.serialize_field("hsl", &self.0.hsl)?;
+            color.serialize_field("code", &self.0.code)?;
+            color.end()
+        }
+    }
+
+    /// Visits a map keyed by `identifiers`, calling `visit_value` with the index of
+    /// each key. Every identifier must occur exactly once.
+    fn visit_keyed_map<'de, A, const N: usize>(
+        mut map: A,
+        kind: &'static str,
+        identifiers: [&'static str; N],
+        mut visit_value: impl FnMut(&mut A, usize) -> Result<(), A::Error>,
+    ) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut seen = [false; N];
+        while let Some(index) = map.next_key_seed(KeySeed { kind, identifiers })? {
+            if core::mem::replace(&mut seen[index], true) {
+                return Err(de::Error::duplicate_field(identifiers[index]));
+            }
+            visit_value(&mut map, index)?;
+        }
+        if let Some(index) = seen.iter().position(|seen| !seen) {
+            return Err(de::Error::missing_field(identifiers[index]));
+        }
+        Ok(())
+    }
+
+    struct PaletteFileVisitor;
+
+    impl<'de> Visitor<'de> for PaletteFileVisitor {
+        type Value = PaletteFile;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a palette.json map of version and flavors")
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut file = PaletteFile::new(PALETTE);
+            let [latte, frappe, macchiato, mocha] = FlavorName::ALL.map(|name| name.identifier());
+            let identifiers = ["version", latte, frappe, macchiato, mocha];
+            visit_keyed_map(map, "flavor", identifiers, |map, index| {
+                match index.checked_sub(1) {
+                    None => file.version = map.next_value()?,
+                    Some(index) => {
+                        let name = FlavorName::ALL[index];
+                        let fields: FlavorFields = map.next_value()?;
+                        file.palette[name] = Flavor {
+                            name,
+                            emoji: fields.emoji,
+                            order: fields.order,
+                            dark: fields.dark,
+                            colors: fields.colors.0,
+                            ansi_colors: fields.ansi_colors.0,
+                        };
+                    }
+                }
+                Ok(())
+            })?;
+            Ok(file)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PaletteFile {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(PaletteFileVisitor)
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct FlavorFields {
+        emoji: char,
+        order: u32,
+        dark: bool,
+        colors: ColorsFields,
+        ansi_colors: AnsiColorsFields,
+    }
+
+    struct ColorsFields(FlavorColors);
+
+    impl<'de> Deserialize<'de> for ColorsFields {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(ColorsVisitor)
+        }
+    }
+
+    struct ColorsVisitor;
+
+    impl<'de> Visitor<'de> for ColorsVisitor {
+        type Value = ColorsFields;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map of colors")
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut colors = PALETTE.latte.colors;
+            let identifiers = ColorName::ALL.map(|name| name.identifier());
+            visit_keyed_map(map, "color", identifiers, |map, index| {
+                let name = ColorName::ALL[index];
+                let fields: ColorFields = map.next_value()?;
+                colors[name] = Color {
+                    name,
+                    order: fields.order,
+                    accent: fields.accent,
+                    hex: fields.hex,
+                    rgb: fields.rgb,
+                    hsl: fields.hsl,
+                };
+                Ok(())
+            })?;
+            Ok(ColorsFields(colors))
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct ColorFields {
+        order: u32,
+        hex: Hex,
+        rgb: Rgb,
+        hsl: Hsl,
+        accent: bool,
+    }
+
+    struct AnsiColorsFields(FlavorAnsiColors);
+
+    impl<'de> Deserialize<'de> for AnsiColorsFields {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(AnsiColorsVisitor)
+        }
+    }
+
+    struct AnsiColorsVisitor;
+
+    impl<'de> Visitor<'de> for AnsiColorsVisitor {
+        type Value = AnsiColorsFields;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map of ANSI color pairs")
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut ansi_colors = PALETTE.latte.ansi_colors;
+            let pairs = ansi_colors.all_pairs();
+            let identifiers = AnsiColorPairName::ALL.map(|name| name.identifier());
+            visit_keyed_map(map, "ANSI color pair", identifiers, |map, index| {
+                let pair = pairs[AnsiColorPairName::ALL[index]];
+                let fields: AnsiColorPairFields = map.next_value()?;
+                for (name, fields) in [
+                    (pair.normal.name, fields.normal),
+                    (pair.bright.name, fields.bright),
+                ] {
+                    ansi_colors[name] = fields.into_ansi_color(name);
+                }
+                Ok(())
+            })?;
+            Ok(AnsiColorsFields(ansi_colors))
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct AnsiColorPairFields {
+        normal: AnsiColorFields,
+        bright: AnsiColorFields,
+    }
+
+    #[derive(Deserialize)]
+    struct AnsiColorFields {
+        hex: Hex,
+        rgb: Rgb,
+        hsl: Hsl,
+        code: u8,
+    }
+
+    impl AnsiColorFields {
+        const fn into_ansi_color(self, name: AnsiColorName) -> AnsiColor {
+            AnsiColor {
+                name,
+                hex: self.hex,
+                rgb: self.rgb,
+                hsl: self.hsl,
+                code: self.code,
+            }
+        }
+    }
+}
@@ -0,0 +1,408 @@
+//! Export of the palette as stylesheets for the web.
+use core::fmt;
+
+use crate::{Color, ColorName, Flavor, FlavorName, Palette};
+
+/// Exports a [`Palette`] as CSS custom properties.
+///
+/// Formatting a `Css` with [`Display`](fmt::Display) produces a stylesheet with:
+///
+/// - a `:root` block defining `--<prefix>-<flavor>-<color>` for every color in
+///   every flavor, along with `-rgb` and `-hsl` variants holding bare channel
+///   values for use with alpha, e.g. `rgb(var(--ctp-mocha-mauve-rgb) / 50%)`;
+/// - a `[data-theme="<flavor>"]` block per flavor, aliasing the flavor's colors as
+///   `--<prefix>-<color>` and setting `color-scheme` from [`Flavor::dark`];
+/// - `prefers-color-scheme` media queries applying the [`Css::light`] and
+///   [`Css::dark`] flavors to pages without a `data-theme` attribute.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{Css, PALETTE};
+///
+/// let css = Css::new(&PALETTE).to_string();
+/// assert!(css.contains("  --ctp-mocha-mauve: #cba6f7;\n"));
+/// assert!(css.contains("  --ctp-mocha-mauve-rgb: 203 166 247;\n"));
+/// assert!(css.contains("  --ctp-mocha-mauve-hsl: 267.4 83.5% 81.0%;\n"));
+/// assert!(css.contains("[data-theme=\"mocha\"] {\n  color-scheme: dark;\n"));
+/// assert!(css.contains("  --ctp-mauve: var(--ctp-mocha-mauve);\n"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Css<'a> {
+    /// The palette to export.
+    pub palette: &'a Palette,
+    /// The prefix of every custom property name, without leading dashes.
+    pub prefix: &'a str,
+    /// The flavor applied when the user prefers a light color scheme, or `None`
+    /// to omit the media query.
+    pub light: Option<FlavorName>,
+    /// The flavor applied when the user prefers a dark color scheme, or `None`
+    /// to omit the media query.
+    pub dark: Option<FlavorName>,
+}
+
+impl<'a> Css<'a> {
+    /// Create a CSS exporter for the given palette with the `ctp` prefix, using
+    /// the first light flavor and the last dark flavor for the `prefers-color-scheme`
+    /// media queries; by default Latte and Mocha.
+    #[must_use]
+    pub fn new(palette: &'a Palette) -> Self {
+        let flavors = palette.all_flavors();
+        Self {
+            palette,
+            prefix: "ctp",
+            light: flavors
+                .iter()
+                .find(|flavor| !flavor.dark)
+                .map(|flavor| flavor.name),
+            dark: flavors
+                .iter()
+                .rev()
+                .find(|flavor| flavor.dark)
+                .map(|flavor| flavor.name),
+        }
+    }
+
+    fn write_aliases(&self, f: &mut fmt::Formatter, flavor: &Flavor, indent: &str) -> fmt::Result {
+        let prefix = self.prefix;
+        let scheme = if flavor.dark { "dark" } else { "light" };
+        writeln!(f, "{indent}color-scheme: {scheme};")?;
+        for color in flavor {
+            let (name, source) = (color.identifier(), flavor.identifier());
+            for suffix in ["", "-rgb", "-hsl"] {
+                writeln!(
+                    f,
+                    "{indent}--{prefix}-{name}{suffix}: var(--{prefix}-{source}-{name}{suffix});"
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Css<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = self.prefix;
+        writeln!(f, ":root {{")?;
+        for flavor in self.palette.all_flavors() {
+            for color in flavor {
+                let name = format_args!("{prefix}-{}-{}", flavor.identifier(), color.identifier());
+                writeln!(f, "  --{name}: {};", color.hex)?;
+                writeln!(f, "  --{name}-rgb: {};", RgbChannels(color))?;
+                writeln!(f, "  --{name}-hsl: {};", HslChannels(color))?;
+            }
+        }
+        writeln!(f, "}}")?;
+
+        for flavor in self.palette.all_flavors() {
+            writeln!(f, "\n[data-theme=\"{}\"] {{", flavor.identifier())?;
+            self.write_aliases(f, flavor, "  ")?;
+            writeln!(f, "}}")?;
+        }
+
+        for (scheme, flavor) in [("light", self.light), ("dark", self.dark)] {
+            let Some(flavor) = flavor else { continue };
+            writeln!(f, "\n@media (prefers-color-scheme: {scheme}) {{")?;
+            writeln!(f, "  :root:not([data-theme]) {{")?;
+            self.write_aliases(f, self.palette.get_flavor(flavor), "    ")?;
+            writeln!(f, "  }}")?;
+            writeln!(f, "}}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The CSS preprocessors supported by [`PreprocessorVariables`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Preprocessor {
+    /// [Sass](https://sass-lang.com/), using the SCSS syntax.
+    Scss,
+    /// [Less](https://lesscss.org/).
+    Less,
+    /// [Stylus](https://stylus-lang.com/).
+    Stylus,
+}
+
+/// Exports a [`Palette`] as CSS preprocessor variables.
+///
+/// Formatting a `PreprocessorVariables` with [`Display`](fmt::Display) produces a
+/// `<prefix>-<flavor>-<color>` variable for every color in every flavor, followed
+/// by a nested map of flavors to colors:
+///
+/// - SCSS: `$ctp-mocha-mauve: #cba6f7;` and a `$catppuccin: (mocha: (mauve: #cba6f7, ...), ...);` map;
+/// - Less: `@ctp-mocha-mauve: #cba6f7;` and a `@catppuccin: { @mocha: { mauve: #cba6f7; ... } ... }`
+///   map, accessed as `@catppuccin[@mocha][mauve]`;
+/// - Stylus: `ctp-mocha-mauve = #cba6f7` and a `catppuccin = { mocha: { mauve: #cba6f7 ... } ... }`
+///   hash, with entries separated by newlines.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{Preprocessor, PreprocessorVariables, PALETTE};
+///
+/// let scss = PreprocessorVariables::new(&PALETTE, Preprocessor::Scss).to_string();
+/// assert!(scss.contains("$ctp-mocha-mauve: #cba6f7;\n"));
+/// assert!(scss.contains("$catppuccin: (\n  latte: (\n    rosewater: #dc8a78,\n"));
+///
+/// let less = PreprocessorVariables::new(&PALETTE, Preprocessor::Less).to_string();
+/// assert!(less.contains("@ctp-mocha-mauve: #cba6f7;\n"));
+///
+/// let stylus = PreprocessorVariables::new(&PALETTE, Preprocessor::Stylus).to_string();
+/// assert!(stylus.contains("ctp-mocha-mauve = #cba6f7\n"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct PreprocessorVariables<'a> {
+    /// The palette to export.
+    pub palette: &'a Palette,
+    /// The preprocessor whose syntax is used.
+    pub preprocessor: Preprocessor,
+    /// The prefix of every variable name.
+    pub prefix: &'a str,
+    /// The name of the map of flavors to colors.
+    pub map: &'a str,
+}
+
+impl<'a> PreprocessorVariables<'a> {
+    /// Create a preprocessor variable exporter for the given palette with the
+    /// `ctp` variable prefix and the `catppuccin` map name.
+    #[must_use]
+    pub const fn new(palette: &'a Palette, preprocessor: Preprocessor) -> Self {
+        Self {
+            palette,
+            preprocessor,
+            prefix: "ctp",
+            map: "catppuccin",
+        }
+    }
+}
+
+impl fmt::Display for PreprocessorVariables<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (prefix, map) = (self.prefix, self.map);
+        for flavor in self.palette.all_flavors() {
+            for color in flavor {
+                let name = format_args!("{prefix}-{}-{}", flavor.identifier(), color.identifier());
+                match self.preprocessor {
+                    Preprocessor::Scss => writeln!(f, "${name}: {};", color.hex)?,
+                    Preprocessor::Less => writeln!(f, "@{name}: {};", color.hex)?,
+                    Preprocessor::Stylus => writeln!(f, "{name} = {}", color.hex)?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        let (open, close, separator) = match self.preprocessor {
+            Preprocessor::Scss => (format_args!("${map}: ("), ");", ","),
+            Preprocessor::Less => (format_args!("@{map}: {{"), "}", ";"),
+            Preprocessor::Stylus => (format_args!("{map} = {{"), "}", ""),
+        };
+        writeln!(f, "{open}")?;
+        for flavor in self.palette.all_flavors() {
+            match self.preprocessor {
+                Preprocessor::Scss => writeln!(f, "  {}: (", flavor.identifier())?,
+                Preprocessor::Less => writeln!(f, "  @{}: {{", flavor.identifier())?,
+                Preprocessor::Stylus => writeln!(f, "  {}: {{", flavor.identifier())?,
+            }
+            for color in flavor {
+                writeln!(f, "    {}: {}{separator}", color.identifier(), color.hex)?;
+            }
+            match self.preprocessor {
+                Preprocessor::Scss => writeln!(f, "  ),")?,
+                Preprocessor::Less | Preprocessor::Stylus => writeln!(f, "  }}")?,
+            }
+        }
+        writeln!(f, "{close}")
+    }
+}
+
+/// The output formats supported by [`Tailwind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TailwindFormat {
+    /// A Tailwind CSS v3 preset as a JavaScript module, for the `presets` array of
+    /// `tailwind.config.js`.
+    V3Preset,
+    /// A Tailwind CSS v3 preset as JSON, which can be loaded with `require`.
+    V3Json,
+    /// A Tailwind CSS v4 `@theme` block, for inclusion in a stylesheet.
+    V4Theme,
+}
+
+/// Exports a [`Palette`] as a Tailwind CSS theme.
+///
+/// By default, every color in every flavor is added as `<flavor>-<color>`,
+/// enabling utilities such as `bg-mocha-mauve`.
+///
+/// If [`Tailwind::semantic`] is set, only color names are added, enabling utilities
+/// such as `bg-base` and `text-text`. Their values are read from the same
+/// `--<prefix>-<color>` custom properties as those defined by [`Css`], so the
+/// flavor can be switched at runtime with `data-theme` attributes or media
+/// queries. The given flavor provides default values for the custom properties,
+/// except in [`TailwindFormat::V3Json`], which cannot define them; use [`Css`]
+/// alongside it instead.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{FlavorName, Tailwind, TailwindFormat, PALETTE};
+///
+/// let theme = Tailwind::new(&PALETTE, TailwindFormat::V4Theme).to_string();
+/// assert!(theme.contains("  --color-mocha-mauve: #cba6f7;\n"));
+///
+/// let preset = Tailwind {
+///     semantic: Some(FlavorName::Mocha),
+///     ..Tailwind::new(&PALETTE, TailwindFormat::V3Preset)
+/// }
+/// .to_string();
+/// assert!(preset.contains(r#""mauve": "rgb(var(--ctp-mauve-rgb) / <alpha-value>)""#));
+/// assert!(preset.contains(r#""--ctp-mauve-rgb": "203 166 247""#));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Tailwind<'a> {
+    /// The palette to export.
+    pub palette: &'a Palette,
+    /// The output format.
+    pub format: TailwindFormat,
+    /// The prefix of the custom properties read by semantic colors, without
+    /// leading dashes.
+    pub prefix: &'a str,
+    /// The flavor whose colors are the defaults for semantic color names, or
+    /// `None` to add every flavor's colors under the flavor's name.
+    pub semantic: Option<FlavorName>,
+}
+
+impl<'a> Tailwind<'a> {
+    /// Create a Tailwind CSS exporter for the given palette in the given format,
+    /// adding every flavor's colors, with the `ctp` prefix.
+    #[must_use]
+    pub const fn new(palette: &'a Palette, format: TailwindFormat) -> Self {
+        Self {
+            palette,
+            format,
+            prefix: "ctp",
+            semantic: None,
+        }
+    }
+
+    fn write_v4_theme(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = self.prefix;
+        let Some(flavor) = self.semantic else {
+            writeln!(f, "@theme {{")?;
+            for flavor in self.palette.all_flavors() {
+                for color in flavor {
+                    let (flavor, name) = (flavor.identifier(), color.identifier());
+                    writeln!(f, "  --color-{flavor}-{name}: {};", color.hex)?;
+                }
+            }
+            return writeln!(f, "}}");
+        };
+
+        writeln!(f, ":root {{")?;
+        for color in self.palette.get_flavor(flavor) {
+            writeln!(f, "  --{prefix}-{}: {};", color.identifier(), color.hex)?;
+        }
+        writeln!(f, "}}\n")?;
+        writeln!(f, "@theme inline {{")?;
+        for name in ColorName::ALL.map(|name| name.identifier()) {
+            writeln!(f, "  --color-{name}: var(--{prefix}-{name});")?;
+        }
+        writeln!(f, "}}")
+    }
+
+    fn write_v3_preset(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = self.prefix;
+        if self.format == TailwindFormat::V3Preset {
+            write!(f, "module.exports = ")?;
+        }
+        writeln!(f, "{{")?;
+        writeln!(f, "  \"theme\": {{")?;
+        writeln!(f, "    \"extend\": {{")?;
+        writeln!(f, "      \"colors\": {{")?;
+        if self.semantic.is_some() {
+            for (name, comma) in with_commas(ColorName::ALL.map(|name| name.identifier())) {
+                let value = format_args!("rgb(var(--{prefix}-{name}-rgb) / <alpha-value>)");
+                writeln!(f, "        \"{name}\": \"{value}\"{comma}")?;
+            }
+        } else {
+            for (flavor, comma) in with_commas(self.palette.all_flavors()) {
+                writeln!(f, "        \"{}\": {{", flavor.identifier())?;
+                for (color, comma) in with_commas(flavor.colors.all_colors()) {
+                    let name = color.identifier();
+                    writeln!(f, "          \"{name}\": \"{}\"{comma}", color.hex)?;
+                }
+                writeln!(f, "        }}{comma}")?;
+            }
+        }
+        writeln!(f, "      }}")?;
+        writeln!(f, "    }}")?;
+        write!(f, "  }}")?;
+
+        match self.semantic {
+            Some(flavor) if self.format == TailwindFormat::V3Preset => {
+                writeln!(f, ",")?;
+                writeln!(f, "  \"plugins\": [")?;
+                writeln!(f, "    ({{ addBase }}) =>")?;
+                writeln!(f, "      addBase({{")?;
+                writeln!(f, "        \":root\": {{")?;
+                let colors = self.palette.get_flavor(flavor).colors.all_colors();
+                for (color, comma) in with_commas(colors) {
+                    let (name, rgb) = (color.identifier(), RgbChannels(color));
+                    writeln!(f, "          \"--{prefix}-{name}-rgb\": \"{rgb}\"{comma}")?;
+                }
+                writeln!(f, "        }}")?;
+                writeln!(f, "      }})")?;
+                writeln!(f, "  ]")?;
+            }
+            _ => writeln!(f)?,
+        }
+        write!(f, "}}")?;
+        if self.format == TailwindFormat::V3Preset {
+            write!(f, ";")?;
+        }
+        writeln!(f)
+    }
+}
+
+impl fmt::Display for Tailwind<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.format {
+            TailwindFormat::V3Preset | TailwindFormat::V3Json => self.write_v3_preset(f),
+            TailwindFormat::V4Theme => self.write_v4_theme(f),
+        }
+    }
+}
+
+/// Pair each item with the separator that follows it in a JSON list; a comma for
+/// all but the last item.
+fn with_commas<T, const N: usize>(items: [T; N]) -> impl Iterator<Item = (T, &'static str)> {
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| (item, if index + 1 < N { "," } else { "" }))
+}
+
+/// A color's RGB channels, space-separated.
+struct RgbChannels<'a>(&'a Color);
+
+impl fmt::Display for RgbChannels<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rgb = self.0.rgb;
+        write!(f, "{} {} {}", rgb.r, rgb.g, rgb.b)
+    }
+}
+
+/// A color's HSL channels, space-separated, with saturation and lightness as
+/// percentages.
+struct HslChannels<'a>(&'a Color);
+
+impl fmt::Display for HslChannels<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hsl = self.0.hsl;
+        write!(
+            f,
+            "{:.1} {:.1}% {:.1}%",
+            hsl.h,
+            hsl.s * 100.0,
+            hsl.l * 100.0
+        )
+    }
+}
@@ -0,0 +1,968 @@
+//! Palette files for painting and design tools.
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::{Color, ColorName, Flavor, FlavorName, Hex, Hsl, Palette, Rgb};
+
+/// The palette file formats supported by [`Swatches`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwatchFormat {
+    /// A GIMP palette (`.gpl`), as used by GIMP and Inkscape.
+    Gpl,
+    /// A Krita palette (`.kpl`); a zip archive of XML files.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{SwatchFormat, Swatches, PALETTE};
+    ///
+    /// // the entries of a zip archive stored without compression
+    /// fn entries(zip: &[u8]) -> Vec<(&str, &[u8])> {
+    ///     let mut entries = Vec::new();
+    ///     let mut rest = zip;
+    ///     while rest.starts_with(b"PK\x03\x04") {
+    ///         let field = |at: usize| u16::from_le_bytes([rest[at], rest[at + 1]]) as usize;
+    ///         let size = field(18) | field(20) << 16;
+    ///         let (name, extra) = (field(26), field(28));
+    ///         let data = 30 + name + extra;
+    ///         entries.push((std::str::from_utf8(&rest[30..30 + name]).unwrap(), &rest[data..data + size]));
+    ///         rest = &rest[data + size..];
+    ///     }
+    ///     entries
+    /// }
+    ///
+    /// let colorset = r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <Colorset version="2.0" name="Catppuccin Mocha" comment="" columns="14" rows="2" readonly="false">
+    ///  <ColorSetEntry name="Rosewater" id="rosewater" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.9607843137254902" g="0.8784313725490196" b="0.8627450980392157"/>
+    ///   <Position row="0" column="0"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Flamingo" id="flamingo" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.9490196078431372" g="0.803921568627451" b="0.803921568627451"/>
+    ///   <Position row="0" column="1"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Pink" id="pink" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.9607843137254902" g="0.7607843137254902" b="0.9058823529411765"/>
+    ///   <Position row="0" column="2"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Mauve" id="mauve" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.796078431372549" g="0.6509803921568628" b="0.9686274509803922"/>
+    ///   <Position row="0" column="3"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Red" id="red" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.9529411764705882" g="0.5450980392156862" b="0.6588235294117647"/>
+    ///   <Position row="0" column="4"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Maroon" id="maroon" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.9215686274509803" g="0.6274509803921569" b="0.6745098039215687"/>
+    ///   <Position row="0" column="5"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Peach" id="peach" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.9803921568627451" g="0.7019607843137254" b="0.5294117647058824"/>
+    ///   <Position row="0" column="6"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Yellow" id="yellow" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.9764705882352941" g="0.8862745098039215" b="0.6862745098039216"/>
+    ///   <Position row="0" column="7"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Green" id="green" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.6509803921568628" g="0.8901960784313725" b="0.6313725490196078"/>
+    ///   <Position row="0" column="8"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Teal" id="teal" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.5803921568627451" g="0.8862745098039215" b="0.8352941176470589"/>
+    ///   <Position row="0" column="9"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Sky" id="sky" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.5372549019607843" g="0.8627450980392157" b="0.9215686274509803"/>
+    ///   <Position row="0" column="10"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Sapphire" id="sapphire" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.4549019607843137" g="0.7803921568627451" b="0.9254901960784314"/>
+    ///   <Position row="0" column="11"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Blue" id="blue" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.5372549019607843" g="0.7058823529411765" b="0.9803921568627451"/>
+    ///   <Position row="0" column="12"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Lavender" id="lavender" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.7058823529411765" g="0.7450980392156863" b="0.996078431372549"/>
+    ///   <Position row="0" column="13"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Text" id="text" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.803921568627451" g="0.8392156862745098" b="0.9568627450980393"/>
+    ///   <Position row="1" column="0"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Subtext 1" id="subtext1" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.7294117647058823" g="0.7607843137254902" b="0.8705882352941177"/>
+    ///   <Position row="1" column="1"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Subtext 0" id="subtext0" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.6509803921568628" g="0.6784313725490196" b="0.7843137254901961"/>
+    ///   <Position row="1" column="2"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Overlay 2" id="overlay2" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.5764705882352941" g="0.6" b="0.6980392156862745"/>
+    ///   <Position row="1" column="3"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Overlay 1" id="overlay1" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.4980392156862745" g="0.5176470588235295" b="0.611764705882353"/>
+    ///   <Position row="1" column="4"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Overlay 0" id="overlay0" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.4235294117647059" g="0.4392156862745098" b="0.5254901960784314"/>
+    ///   <Position row="1" column="5"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Surface 2" id="surface2" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.34509803921568627" g="0.3568627450980392" b="0.4392156862745098"/>
+    ///   <Position row="1" column="6"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Surface 1" id="surface1" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.27058823529411763" g="0.2784313725490196" b="0.35294117647058826"/>
+    ///   <Position row="1" column="7"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Surface 0" id="surface0" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.19215686274509805" g="0.19607843137254902" b="0.26666666666666666"/>
+    ///   <Position row="1" column="8"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Base" id="base" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.11764705882352941" g="0.11764705882352941" b="0.1803921568627451"/>
+    ///   <Position row="1" column="9"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Mantle" id="mantle" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.09411764705882353" g="0.09411764705882353" b="0.1450980392156863"/>
+    ///   <Position row="1" column="10"/>
+    ///  </ColorSetEntry>
+    ///  <ColorSetEntry name="Crust" id="crust" spot="false" bitdepth="U8">
+    ///   <RGB space="sRGB-elle-V2-srgbtrc.icc" r="0.06666666666666667" g="0.06666666666666667" b="0.10588235294117647"/>
+    ///   <Position row="1" column="11"/>
+    ///  </ColorSetEntry>
+    /// </Colorset>
+    /// "#;
+    /// let kpl = Swatches::new(&PALETTE.mocha, SwatchFormat::Kpl).to_bytes();
+    /// assert_eq!(
+    ///     entries(&kpl),
+    ///     [
+    ///         ("mimetype", &b"krita/x-colorset"[..]),
+    ///         ("colorset.xml", colorset.as_bytes()),
+    ///         ("profiles.xml", b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Profiles/>\n"),
+    ///     ],
+    /// );
+    /// ```
+    Kpl,
+    /// A Paint.NET palette (`.txt`), padded with white to the 96 colors
+    /// Paint.NET expects.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{SwatchFormat, Swatches, PALETTE};
+    ///
+    /// let colors = "; paint.net Palette File
+    /// ; Catppuccin Mocha
+    /// ; Rosewater
+    /// FFF5E0DC
+    /// ; Flamingo
+    /// FFF2CDCD
+    /// ; Pink
+    /// FFF5C2E7
+    /// ; Mauve
+    /// FFCBA6F7
+    /// ; Red
+    /// FFF38BA8
+    /// ; Maroon
+    /// FFEBA0AC
+    /// ; Peach
+    /// FFFAB387
+    /// ; Yellow
+    /// FFF9E2AF
+    /// ; Green
+    /// FFA6E3A1
+    /// ; Teal
+    /// FF94E2D5
+    /// ; Sky
+    /// FF89DCEB
+    /// ; Sapphire
+    /// FF74C7EC
+    /// ; Blue
+    /// FF89B4FA
+    /// ; Lavender
+    /// FFB4BEFE
+    /// ; Text
+    /// FFCDD6F4
+    /// ; Subtext 1
+    /// FFBAC2DE
+    /// ; Subtext 0
+    /// FFA6ADC8
+    /// ; Overlay 2
+    /// FF9399B2
+    /// ; Overlay 1
+    /// FF7F849C
+    /// ; Overlay 0
+    /// FF6C7086
+    /// ; Surface 2
+    /// FF585B70
+    /// ; Surface 1
+    /// FF45475A
+    /// ; Surface 0
+    /// FF313244
+    /// ; Base
+    /// FF1E1E2E
+    /// ; Mantle
+    /// FF181825
+    /// ; Crust
+    /// FF11111B
+    /// ";
+    /// let upstream = colors.to_string() + &"FFFFFFFF\n".repeat(96 - 26);
+    /// let txt = Swatches::new(&PALETTE.mocha, SwatchFormat::PaintDotNet).to_bytes();
+    /// assert_eq!(String::from_utf8(txt).unwrap(), upstream);
+    /// ```
+    PaintDotNet,
+    /// An Adobe Swatch Exchange file (`.ase`) holding a single group; see [`Ase`].
+    Ase,
+    /// A Procreate palette (`.swatches`); a zip archive holding a JSON file.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{SwatchFormat, Swatches, PALETTE};
+    ///
+    /// // the entries of a zip archive stored without compression
+    /// fn entries(zip: &[u8]) -> Vec<(&str, &[u8])> {
+    ///     let mut entries = Vec::new();
+    ///     let mut rest = zip;
+    ///     while rest.starts_with(b"PK\x03\x04") {
+    ///         let field = |at: usize| u16::from_le_bytes([rest[at], rest[at + 1]]) as usize;
+    ///         let size = field(18) | field(20) << 16;
+    ///         let (name, extra) = (field(26), field(28));
+    ///         let data = 30 + name + extra;
+    ///         entries.push((std::str::from_utf8(&rest[30..30 + name]).unwrap(), &rest[data..data + size]));
+    ///         rest = &rest[data + size..];
+    ///     }
+    ///     entries
+    /// }
+    ///
+    /// let json = concat!(
+    ///     r#"[{"name":"Catppuccin Mocha","swatches":["#,
+    ///     r#"{"hue":0.026666666666666578,"saturation":0.10204081632653059,"brightness":0.9607843137254902,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0,"saturation":0.152892561983471,"brightness":0.9490196078431372,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.8790849673202613,"saturation":0.2081632653061225,"brightness":0.9607843137254902,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.742798353909465,"saturation":0.32793522267206476,"brightness":0.9686274509803922,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.9535256410256411,"saturation":0.4279835390946502,"brightness":0.9529411764705882,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.9733333333333333,"saturation":0.3191489361702127,"brightness":0.9215686274509803,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.06376811594202897,"saturation":0.45999999999999996,"brightness":0.9803921568627451,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.11486486486486486,"saturation":0.2971887550200803,"brightness":0.9764705882352941,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.32070707070707066,"saturation":0.2907488986784141,"brightness":0.8901960784313725,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.4722222222222222,"saturation":0.3451327433628318,"brightness":0.8862745098039215,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.5255102040816326,"saturation":0.41702127659574467,"brightness":0.9215686274509803,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.5513888888888889,"saturation":0.5084745762711864,"brightness":0.9254901960784314,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.603244837758112,"saturation":0.452,"brightness":0.9803921568627451,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6441441441441442,"saturation":0.29133858267716534,"brightness":0.996078431372549,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6282051282051282,"saturation":0.1598360655737705,"brightness":0.9568627450980393,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6296296296296297,"saturation":0.16216216216216223,"brightness":0.8705882352941177,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6323529411764706,"saturation":0.16999999999999996,"brightness":0.7843137254901961,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6344086021505376,"saturation":0.17415730337078655,"brightness":0.6980392156862745,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6379310344827586,"saturation":0.18589743589743596,"brightness":0.611764705882353,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6410256410256411,"saturation":0.1940298507462687,"brightness":0.5254901960784314,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6458333333333334,"saturation":0.21428571428571427,"brightness":0.4392156862745098,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6507936507936508,"saturation":0.23333333333333342,"brightness":0.35294117647058826,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6578947368421053,"saturation":0.2794117647058823,"brightness":0.26666666666666666,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6666666666666666,"saturation":0.34782608695652173,"brightness":0.1803921568627451,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6666666666666666,"saturation":0.3513513513513514,"brightness":0.1450980392156863,"alpha":1,"colorSpace":0},"#,
+    ///     r#"{"hue":0.6666666666666666,"saturation":0.37037037037037035,"brightness":0.10588235294117647,"alpha":1,"colorSpace":0}"#,
+    ///     "]}]",
+    /// );
+    /// let swatches = Swatches::new(&PALETTE.mocha, SwatchFormat::Procreate).to_bytes();
+    /// assert_eq!(entries(&swatches), [("Swatches.json", json.as_bytes())]);
+    /// ```
+    Procreate,
+}
+
+impl SwatchFormat {
+    /// Get the file extension of the format, without a leading dot.
+    #[must_use]
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Gpl => "gpl",
+            Self::Kpl => "kpl",
+            Self::PaintDotNet => "txt",
+            Self::Ase => "ase",
+            Self::Procreate => "swatches",
+        }
+    }
+}
+
+/// Exports a flavor as a palette file for painting tools.
+///
+/// The palette is named after the flavor, such as "Catppuccin Mocha", and holds
+/// the flavor's colors in order of [`Color::order`], each named by the
+/// [`Display`](core::fmt::Display) implementation of its [`ColorName`](crate::ColorName).
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{SwatchFormat, Swatches, PALETTE};
+///
+/// let gpl = Swatches::new(&PALETTE.mocha, SwatchFormat::Gpl).to_bytes();
+/// let gpl = String::from_utf8(gpl).unwrap();
+/// assert!(gpl.starts_with("GIMP Palette\nName: Catppuccin Mocha\n#\n245 224 220\tRosewater\n"));
+///
+/// let kpl = Swatches::new(&PALETTE.mocha, SwatchFormat::Kpl).to_bytes();
+/// assert!(kpl.starts_with(b"PK\x03\x04"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Swatches<'a> {
+    /// The flavor to export.
+    pub flavor: &'a Flavor,
+    /// The output format.
+    pub format: SwatchFormat,
+}
+
+impl<'a> Swatches<'a> {
+    /// Create a palette file exporter for the given flavor in the given format.
+    #[must_use]
+    pub const fn new(flavor: &'a Flavor, format: SwatchFormat) -> Self {
+        Self { flavor, format }
+    }
+
+    /// Write the palette file.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self.format {
+            SwatchFormat::Gpl => self.to_gpl().into_bytes(),
+            SwatchFormat::Kpl => self.to_kpl(),
+            SwatchFormat::PaintDotNet => self.to_paint_dot_net().into_bytes(),
+            SwatchFormat::Ase => Ase::from(self.flavor).to_bytes(),
+            SwatchFormat::Procreate => self.to_procreate(),
+        }
+    }
+
+    fn colors(&self) -> [&'a Color; 26] {
+        colors_in_order(self.flavor)
+    }
+
+    fn to_gpl(self) -> String {
+        let mut gpl = String::new();
+        // writing to a `String` never fails
+        let _ = writeln!(
+            gpl,
+            "GIMP Palette\nName: Catppuccin {}\n#",
+            self.flavor.name
+        );
+        for color in self.colors() {
+            let (rgb, name) = (color.rgb, color.name);
+            let _ = writeln!(gpl, "{:>3} {:>3} {:>3}\t{name}", rgb.r, rgb.g, rgb.b);
+        }
+        gpl
+    }
+
+    fn to_paint_dot_net(self) -> String {
+        // Paint.NET palettes always have this many entries
+        const ENTRIES: usize = 96;
+        let colors = self.colors();
+
+        let mut txt = String::new();
+        let _ = writeln!(
+            txt,
+            "; paint.net Palette File\n; Catppuccin {}",
+            self.flavor.name
+        );
+        for color in colors {
+            let (rgb, name) = (color.rgb, color.name);
+            let _ = writeln!(txt, "; {name}\nFF{:02X}{:02X}{:02X}", rgb.r, rgb.g, rgb.b);
+        }
+        // pad with opaque white, as Paint.NET does
+        for _ in colors.len()..ENTRIES {
+            txt.push_str("FFFFFFFF\n");
+        }
+        txt
+    }
+
+    fn to_kpl(self) -> Vec<u8> {
+        // accents on the first row, monochromatic colors on the second
+        const COLUMNS: usize = 14;
+        let colors = self.colors();
+
+        let mut colorset = String::new();
+        let _ = writeln!(
+            colorset,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <Colorset version=\"2.0\" name=\"Catppuccin {}\" comment=\"\" columns=\"{COLUMNS}\" rows=\"{}\" readonly=\"false\">",
+            self.flavor.name,
+            colors.len().div_ceil(COLUMNS)
+        );
+        for (index, color) in colors.into_iter().enumerate() {
+            let [r, g, b] = [color.rgb.r, color.rgb.g, color.rgb.b].map(|c| f64::from(c) / 255.0);
+            let _ = writeln!(
+                colorset,
+                " <ColorSetEntry name=\"{}\" id=\"{}\" spot=\"false\" bitdepth=\"U8\">\n  \
+                 <RGB space=\"sRGB-elle-V2-srgbtrc.icc\" r=\"{r}\" g=\"{g}\" b=\"{b}\"/>\n  \
+                 <Position row=\"{}\" column=\"{}\"/>\n \
+                 </ColorSetEntry>",
+                color.name,
+                color.identifier(),
+                index / COLUMNS,
+                index % COLUMNS
+            );
+        }
+        colorset.push_str("</Colorset>\n");
+
+        zip(&[
+            ("mimetype", b"krita/x-colorset"),
+            ("colorset.xml", colorset.as_bytes()),
+            (
+                "profiles.xml",
+                b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Profiles/>\n",
+            ),
+        ])
+    }
+
+    fn to_procreate(self) -> Vec<u8> {
+        let mut json = String::new();
+        let _ = write!(
+            json,
+            "[{{\"name\":\"Catppuccin {}\",\"swatches\":[",
+            self.flavor.name
+        );
+        for (index, color) in self.colors().into_iter().enumerate() {
+            let separator = if index == 0 { "" } else { "," };
+            let [hue, saturation, brightness] = hsb(color);
+            let _ = write!(
+                json,
+                "{separator}{{\"hue\":{hue},\"saturation\":{saturation},\"brightness\":{brightness},\"alpha\":1,\"colorSpace\":0}}"
+            );
+        }
+        json.push_str("]}]");
+
+        zip(&[("Swatches.json", json.as_bytes())])
+    }
+}
+
+/// The flavor's colors, in order of [`Color::order`].
+fn colors_in_order(flavor: &Flavor) -> [&Color; 26] {
+    let mut colors = flavor.colors.all_colors();
+    colors.sort_unstable_by_key(|color| (color.order, color.name));
+    colors
+}
+
+/// The hue, saturation and brightness of a color, each in the range 0-1.
+fn hsb(color: &Color) -> [f64; 3] {
+    let [r, g, b] = [color.rgb.r, color.rgb.g, color.rgb.b].map(|c| f64::from(c) / 255.0);
+    let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+    let saturation = if max > 0.0 { (max - min) / max } else { 0.0 };
+    [Hsl::from(color.rgb).h / 360.0, saturation, max]
+}
+
+/// An Adobe Swatch Exchange (`.ase`) file, as read and written by Photoshop and
+/// Illustrator.
+///
+/// Converting a [`Palette`] gives one group per flavor, named like
+/// "Catppuccin Mocha", holding the flavor's colors in order of
+/// [`Color::order`] as named RGB swatches. Converting a single [`Flavor`]
+/// gives just its group.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{Ase, PALETTE};
+///
+/// let ase = Ase::from(&PALETTE);
+/// assert_eq!(ase.groups.len(), 4);
+/// assert_eq!(ase.groups[3].name, "Catppuccin Mocha");
+/// assert_eq!(ase.groups[3].swatches[0].name, "Rosewater");
+///
+/// let bytes = ase.to_bytes();
+/// assert_eq!(Ase::from_bytes(&bytes), Ok(ase));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ase {
+    /// Swatches that are not part of any group.
+    pub swatches: Vec<AseSwatch>,
+    /// Named groups of swatches.
+    pub groups: Vec<AseGroup>,
+}
+
+/// A named group of swatches in an [`Ase`] file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AseGroup {
+    /// The name of the group.
+    ///
+    /// Names longer than 65534 UTF-16 code units are truncated when written.
+    pub name: String,
+    /// The swatches in the group.
+    pub swatches: Vec<AseSwatch>,
+}
+
+/// A named RGB swatch in an [`Ase`] file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AseSwatch {
+    /// The name of the swatch.
+    ///
+    /// Names longer than 65534 UTF-16 code units are truncated when written.
+    pub name: String,
+    /// The color of the swatch.
+    pub rgb: Rgb,
+}
+
+/// Error type for reading an [`Ase`] file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseAseError {
+    offset: usize,
+    reason: &'static str,
+}
+
+impl core::error::Error for ParseAseError {}
+impl fmt::Display for ParseAseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid ASE file at byte {}: {}",
+            self.offset, self.reason
+        )
+    }
+}
+
+impl ParseAseError {
+    const fn new(offset: usize, reason: &'static str) -> Self {
+        Self { offset, reason }
+    }
+
+    /// The offset of the byte at which reading failed.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// let error = catppuccin::Ase::from_bytes(b"ASEF\0\x01").unwrap_err();
+    /// assert_eq!(error.offset(), 6);
+    /// assert_eq!(error.to_string(), "invalid ASE file at byte 6: unexpected end of file");
+    /// ```
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+const ASE_GROUP_START: u16 = 0xc001;
+const ASE_GROUP_END: u16 = 0xc002;
+const ASE_COLOR: u16 = 0x0001;
+
+impl Ase {
+    /// Write the file.
+    ///
+    /// Swatches are written as process colors in the RGB color model. Names
+    /// longer than the format allows are truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file would have more than [`u32::MAX`] blocks (one per
+    /// swatch and two per group), which the format cannot represent.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{Ase, AseGroup, AseSwatch, Rgb};
+    ///
+    /// let ase = Ase {
+    ///     swatches: Vec::new(),
+    ///     groups: vec![AseGroup {
+    ///         name: "G".to_string(),
+    ///         swatches: vec![AseSwatch {
+    ///             name: "W".to_string(),
+    ///             rgb: Rgb { r: 255, g: 0, b: 0 },
+    ///         }],
+    ///     }],
+    /// };
+    /// assert_eq!(
+    ///     ase.to_bytes(),
+    ///     b"ASEF\0\x01\0\0\0\0\0\x03\
+    ///       \xc0\x01\0\0\0\x06\0\x02\0G\0\0\
+    ///       \0\x01\0\0\0\x18\0\x02\0W\0\0RGB \x3f\x80\0\0\0\0\0\0\0\0\0\0\0\x02\
+    ///       \xc0\x02\0\0\0\0",
+    /// );
+    ///
+    /// let long = Ase {
+    ///     swatches: vec![AseSwatch {
+    ///         name: "W".repeat(70_000),
+    ///         rgb: Rgb { r: 255, g: 0, b: 0 },
+    ///     }],
+    ///     groups: Vec::new(),
+    /// };
+    /// let read = Ase::from_bytes(&long.to_bytes()).unwrap();
+    /// assert_eq!(read.swatches[0].name.len(), 65_534);
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let blocks = self.swatches.len()
+            + self
+                .groups
+                .iter()
+                .map(|group| group.swatches.len() + 2)
+                .sum::<usize>();
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"ASEF");
+        for field in [1, 0] {
+            bytes.extend_from_slice(&u16::to_be_bytes(field));
+        }
+        bytes.extend_from_slice(&ase_u32(blocks).to_be_bytes());
+
+        for swatch in &self.swatches {
+            write_ase_swatch(&mut bytes, swatch);
+        }
+        for group in &self.groups {
+            write_ase_block(&mut bytes, ASE_GROUP_START, &ase_name(&group.name));
+            for swatch in &group.swatches {
+                write_ase_swatch(&mut bytes, swatch);
+            }
+            write_ase_block(&mut bytes, ASE_GROUP_END, &[]);
+        }
+        bytes
+    }
+
+    /// Read a file.
+    ///
+    /// Blocks of unknown types are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is malformed, if groups are nested or
+    /// unbalanced, or if a swatch uses a color model other than RGB.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseAseError> {
+        let mut reader = AseReader { bytes, offset: 0 };
+        if reader.take(4)? != b"ASEF" {
+            return Err(ParseAseError::new(0, "missing `ASEF` signature"));
+        }
+        let major = reader.u16()?;
+        if major != 1 {
+            return Err(ParseAseError::new(4, "unsupported version"));
+        }
+        reader.u16()?;
+        let blocks = reader.u32()?;
+
+        let mut ase = Self::default();
+        let mut group: Option<AseGroup> = None;
+        for _ in 0..blocks {
+            let start = reader.offset;
+            let kind = reader.u16()?;
+            let len = reader.u32()? as usize;
+            let body = reader.offset;
+            reader.take(len)?;
+            // offsets within a block stay relative to the whole file
+            let mut block = AseReader {
+                bytes: &bytes[..body + len],
+                offset: body,
+            };
+
+            match kind {
+                ASE_GROUP_START if group.is_some() => {
+                    return Err(ParseAseError::new(start, "nested group"));
+                }
+                ASE_GROUP_START => {
+                    group = Some(AseGroup {
+                        name: block.name()?,
+                        swatches: Vec::new(),
+                    });
+                }
+                ASE_GROUP_END => {
+                    let Some(group) = group.take() else {
+                        return Err(ParseAseError::new(start, "group end without group start"));
+                    };
+                    ase.groups.push(group);
+                }
+                ASE_COLOR => {
+                    let swatch = block.swatch()?;
+                    group
+                        .as_mut()
+                        .map_or(&mut ase.swatches, |group| &mut group.swatches)
+                        .push(swatch);
+                }
+                _ => {}
+            }
+        }
+
+        if group.is_some() {
+            return Err(ParseAseError::new(reader.offset, "unterminated group"));
+        }
+        Ok(ase)
+    }
+
+    /// Apply the file's colors to a copy of `base`.
+    ///
+    /// Groups are matched to flavors by name, with or without a leading
+    /// "Catppuccin ", and swatches to colors by name. Groups and swatches
+    /// that match nothing are ignored, as are ungrouped swatches.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{Ase, Rgb, PALETTE};
+    ///
+    /// let mut ase = Ase::from(&PALETTE);
+    /// assert_eq!(ase.to_palette(&PALETTE), PALETTE);
+    ///
+    /// ase.groups[3].swatches[0].rgb = Rgb { r: 255, g: 255, b: 255 };
+    /// let palette = ase.to_palette(&PALETTE);
+    /// assert_eq!(palette.mocha.colors.rosewater.hex.to_string(), "#ffffff");
+    /// assert_eq!(palette.mocha.colors.flamingo, PALETTE.mocha.colors.flamingo);
+    /// ```
+    #[must_use]
+    pub fn to_palette(&self, base: &Palette) -> Palette {
+        let mut palette = *base;
+        for group in &self.groups {
+            let name = group
+                .name
+                .strip_prefix("Catppuccin ")
+                .unwrap_or(&group.name);
+            let Ok(flavor) = name.parse::<FlavorName>() else {
+                continue;
+            };
+            for swatch in &group.swatches {
+                let Ok(name) = swatch.name.parse::<ColorName>() else {
+                    continue;
+                };
+                let color = &mut palette[flavor].colors[name];
+                if color.rgb != swatch.rgb {
+                    color.rgb = swatch.rgb;
+                    color.hex = Hex(swatch.rgb);
+                    color.hsl = Hsl::from(swatch.rgb);
+                }
+            }
+        }
+        palette
+    }
+}
+
+impl From<&Flavor> for AseGroup {
+    fn from(flavor: &Flavor) -> Self {
+        Self {
+            name: alloc::format!("Catppuccin {}", flavor.name),
+            swatches: colors_in_order(flavor)
+                .into_iter()
+                .map(|color| AseSwatch {
+                    name: alloc::format!("{}", color.name),
+                    rgb: color.rgb,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Flavor> for Ase {
+    fn from(flavor: &Flavor) -> Self {
+        Self {
+            swatches: Vec::new(),
+            groups: alloc::vec![flavor.into()],
+        }
+    }
+}
+
+impl From<&Palette> for Ase {
+    fn from(palette: &Palette) -> Self {
+        Self {
+            swatches: Vec::new(),
+            groups: palette.all_flavors().into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+fn write_ase_block(bytes: &mut Vec<u8>, kind: u16, body: &[u8]) {
+    bytes.extend_from_slice(&kind.to_be_bytes());
+    bytes.extend_from_slice(&ase_u32(body.len()).to_be_bytes());
+    bytes.extend_from_slice(body);
+}
+
+fn write_ase_swatch(bytes: &mut Vec<u8>, swatch: &AseSwatch) {
+    let mut body = ase_name(&swatch.name);
+    body.extend_from_slice(b"RGB ");
+    for channel in [swatch.rgb.r, swatch.rgb.g, swatch.rgb.b] {
+        body.extend_from_slice(&(f32::from(channel) / 255.0).to_be_bytes());
+    }
+    // a process color, rather than a global or spot color
+    body.extend_from_slice(&2_u16.to_be_bytes());
+    write_ase_block(bytes, ASE_COLOR, &body);
+}
+
+/// A length-prefixed, null-terminated UTF-16 string.
+///
+/// The length is a 16-bit count that includes the terminator, so names are
+/// truncated to the last whole character that fits in 65534 code units.
+fn ase_name(name: &str) -> Vec<u8> {
+    let mut units = Vec::new();
+    for c in name.chars() {
+        let mut buffer = [0; 2];
+        let encoded = c.encode_utf16(&mut buffer);
+        if units.len() + encoded.len() >= usize::from(u16::MAX) {
+            break;
+        }
+        units.extend_from_slice(encoded);
+    }
+    units.push(0);
+
+    let mut bytes = Vec::with_capacity(2 + units.len() * 2);
+    // at most `u16::MAX` units, including the terminator
+    bytes.extend_from_slice(&u16::try_from(units.len()).unwrap_or(u16::MAX).to_be_bytes());
+    for unit in units {
+        bytes.extend_from_slice(&unit.to_be_bytes());
+    }
+    bytes
+}
+
+/// Panics if the value does not fit in 32 bits. Block bodies are bounded by
+/// the truncated name, so this can only fail for the number of blocks.
+fn ase_u32(value: usize) -> u32 {
+    u32::try_from(value).expect("ASE field fits in 32 bits")
+}
+
+struct AseReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> AseReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ParseAseError> {
+        let bytes = self
+            .bytes
+            .get(self.offset..)
+            .and_then(|rest| rest.get(..len))
+            .ok_or_else(|| {
+                ParseAseError::new(self.offset.min(self.bytes.len()), "unexpected end of file")
+            })?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ParseAseError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u16(&mut self) -> Result<u16, ParseAseError> {
+        self.array().map(u16::from_be_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, ParseAseError> {
+        self.array().map(u32::from_be_bytes)
+    }
+
+    fn f32(&mut self) -> Result<f32, ParseAseError> {
+        self.array().map(f32::from_be_bytes)
+    }
+
+    fn name(&mut self) -> Result<String, ParseAseError> {
+        let start = self.offset;
+        let len = self.u16()?;
+        let mut units = Vec::with_capacity(len.into());
+        for _ in 0..len {
+            units.push(self.u16()?);
+        }
+        if units.last() == Some(&0) {
+            units.pop();
+        }
+        char::decode_utf16(units)
+            .collect::<Result<_, _>>()
+            .map_err(|_| ParseAseError::new(start, "invalid UTF-16 in name"))
+    }
+
+    fn swatch(&mut self) -> Result<AseSwatch, ParseAseError> {
+        let name = self.name()?;
+        let model = self.offset;
+        if self.take(4)? != b"RGB " {
+            return Err(ParseAseError::new(
+                model,
+                "unsupported color model, expected RGB",
+            ));
+        }
+        let [r, g, b] = [self.f32()?, self.f32()?, self.f32()?].map(ase_channel);
+        Ok(AseSwatch {
+            name,
+            rgb: Rgb { r, g, b },
+        })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn ase_channel(value: f32) -> u8 {
+    // `NaN` clamps to `NaN`, which casts to 0
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// Write a zip archive of the given files, stored without compression.
+fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+    // 1980-01-01, the earliest date zip can represent
+    const DATE: u16 = (1 << 5) | 1;
+
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+    for (name, contents) in files {
+        let offset = zip_u32(archive.len());
+        let (name_len, size, crc) = (
+            zip_u16(name.len()),
+            zip_u32(contents.len()),
+            crc32(contents),
+        );
+
+        // local file header
+        archive.extend_from_slice(&0x0403_4b50_u32.to_le_bytes());
+        for field in [10, 0, 0, 0, DATE] {
+            archive.extend_from_slice(&u16::to_le_bytes(field));
+        }
+        for field in [crc, size, size] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [name_len, 0] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(contents);
+
+        // central directory file header
+        directory.extend_from_slice(&0x0201_4b50_u32.to_le_bytes());
+        for field in [20, 10, 0, 0, 0, DATE] {
+            directory.extend_from_slice(&u16::to_le_bytes(field));
+        }
+        for field in [crc, size, size] {
+            directory.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [name_len, 0, 0, 0, 0] {
+            directory.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [0, offset] {
+            directory.extend_from_slice(&u32::to_le_bytes(field));
+        }
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    // end of central directory record
+    let (count, directory_offset) = (zip_u16(files.len()), zip_u32(archive.len()));
+    let directory_size = zip_u32(directory.len());
+    archive.append(&mut directory);
+    archive.extend_from_slice(&0x0605_4b50_u32.to_le_bytes());
+    for field in [0, 0, count, count] {
+        archive.extend_from_slice(&u16::to_le_bytes(field));
+    }
+    for field in [directory_size, directory_offset] {
+        archive.extend_from_slice(&field.to_le_bytes());
+    }
+    archive.extend_from_slice(&0_u16.to_le_bytes());
+    archive
+}
+
+// The archives written by this module hold a few small files with fixed
+// names, so these conversions cannot fail in practice.
+fn zip_u16(value: usize) -> u16 {
+    u16::try_from(value).expect("zip field fits in 16 bits")
+}
+
+fn zip_u32(value: usize) -> u32 {
+    u32::try_from(value).expect("zip field fits in 32 bits")
+}
+
+/// The CRC-32 checksum of `bytes`, as used by zip.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
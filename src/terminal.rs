@@ -0,0 +1,644 @@
+//! Terminal emulator themes.
+use core::fmt;
+
+use crate::{AnsiColor, Flavor, Hex, Rgb};
+
+/// The terminal emulators supported by [`TerminalTheme`].
+///
+/// Each format reproduces the corresponding official Catppuccin port.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminalFormat {
+    /// An [Alacritty](https://alacritty.org/) TOML configuration file, as in
+    /// [catppuccin/alacritty](https://github.com/catppuccin/alacritty).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{TerminalFormat, TerminalTheme, PALETTE};
+    ///
+    /// let upstream = r##"[colors.primary]
+    /// background = "#1e1e2e"
+    /// foreground = "#cdd6f4"
+    /// dim_foreground = "#7f849c"
+    /// bright_foreground = "#cdd6f4"
+    ///
+    /// [colors.cursor]
+    /// text = "#1e1e2e"
+    /// cursor = "#f5e0dc"
+    ///
+    /// [colors.vi_mode_cursor]
+    /// text = "#1e1e2e"
+    /// cursor = "#b4befe"
+    ///
+    /// [colors.search.matches]
+    /// foreground = "#1e1e2e"
+    /// background = "#a6adc8"
+    ///
+    /// [colors.search.focused_match]
+    /// foreground = "#1e1e2e"
+    /// background = "#a6e3a1"
+    ///
+    /// [colors.footer_bar]
+    /// foreground = "#1e1e2e"
+    /// background = "#a6adc8"
+    ///
+    /// [colors.hints.start]
+    /// foreground = "#1e1e2e"
+    /// background = "#f9e2af"
+    ///
+    /// [colors.hints.end]
+    /// foreground = "#1e1e2e"
+    /// background = "#a6adc8"
+    ///
+    /// [colors.selection]
+    /// text = "#1e1e2e"
+    /// background = "#f5e0dc"
+    ///
+    /// [colors.normal]
+    /// black = "#45475a"
+    /// red = "#f38ba8"
+    /// green = "#a6e3a1"
+    /// yellow = "#f9e2af"
+    /// blue = "#89b4fa"
+    /// magenta = "#f5c2e7"
+    /// cyan = "#94e2d5"
+    /// white = "#a6adc8"
+    ///
+    /// [colors.bright]
+    /// black = "#585b70"
+    /// red = "#f37799"
+    /// green = "#89d88b"
+    /// yellow = "#ebd391"
+    /// blue = "#74a8fc"
+    /// magenta = "#f2aede"
+    /// cyan = "#6bd7ca"
+    /// white = "#bac2de"
+    ///
+    /// [colors.dim]
+    /// black = "#45475a"
+    /// red = "#f38ba8"
+    /// green = "#a6e3a1"
+    /// yellow = "#f9e2af"
+    /// blue = "#89b4fa"
+    /// magenta = "#f5c2e7"
+    /// cyan = "#94e2d5"
+    /// white = "#a6adc8"
+    ///
+    /// [[colors.indexed_colors]]
+    /// index = 16
+    /// color = "#fab387"
+    ///
+    /// [[colors.indexed_colors]]
+    /// index = 17
+    /// color = "#f5e0dc"
+    /// "##;
+    /// let theme = TerminalTheme::new(&PALETTE.mocha, TerminalFormat::Alacritty);
+    /// assert_eq!(theme.to_string(), upstream);
+    /// ```
+    Alacritty,
+    /// A [kitty](https://sw.kovidgoyal.net/kitty/) theme `.conf` file, as in
+    /// [catppuccin/kitty](https://github.com/catppuccin/kitty).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{TerminalFormat, TerminalTheme, PALETTE};
+    ///
+    /// let upstream = "# vim:ft=kitty
+    ///
+    /// ### name:     Catppuccin Kitty Mocha
+    /// ### author:   Catppuccin Org
+    /// ### license:  MIT
+    /// ### upstream: https://github.com/catppuccin/kitty/blob/main/themes/mocha.conf
+    /// ### blurb:    Soothing pastel theme for the high-spirited!
+    ///
+    ///
+    ///
+    /// ## The basic colors
+    /// foreground              #cdd6f4
+    /// background              #1e1e2e
+    /// selection_foreground    #1e1e2e
+    /// selection_background    #f5e0dc
+    ///
+    /// ## Cursor colors
+    /// cursor                  #f5e0dc
+    /// cursor_text_color       #1e1e2e
+    ///
+    /// ## URL underline color when hovering with mouse
+    /// url_color               #f5e0dc
+    ///
+    /// ## Kitty window border colors
+    /// active_border_color     #b4befe
+    /// inactive_border_color   #6c7086
+    /// bell_border_color       #f9e2af
+    ///
+    /// ## OS Window titlebar colors
+    /// wayland_titlebar_color system
+    /// macos_titlebar_color system
+    ///
+    /// ## Tab bar colors
+    /// active_tab_foreground   #11111b
+    /// active_tab_background   #cba6f7
+    /// inactive_tab_foreground #cdd6f4
+    /// inactive_tab_background #181825
+    /// tab_bar_background      #11111b
+    ///
+    /// ## Colors for marks (marked text in the terminal)
+    /// mark1_foreground #1e1e2e
+    /// mark1_background #b4befe
+    /// mark2_foreground #1e1e2e
+    /// mark2_background #cba6f7
+    /// mark3_foreground #1e1e2e
+    /// mark3_background #74c7ec
+    ///
+    /// ## The 16 terminal colors
+    ///
+    /// ## black
+    /// color0 #45475a
+    /// color8 #585b70
+    ///
+    /// ## red
+    /// color1 #f38ba8
+    /// color9 #f37799
+    ///
+    /// ## green
+    /// color2  #a6e3a1
+    /// color10 #89d88b
+    ///
+    /// ## yellow
+    /// color3  #f9e2af
+    /// color11 #ebd391
+    ///
+    /// ## blue
+    /// color4  #89b4fa
+    /// color12 #74a8fc
+    ///
+    /// ## magenta
+    /// color5  #f5c2e7
+    /// color13 #f2aede
+    ///
+    /// ## cyan
+    /// color6  #94e2d5
+    /// color14 #6bd7ca
+    ///
+    /// ## white
+    /// color7  #a6adc8
+    /// color15 #bac2de
+    ///
+    /// ## extended colors
+    /// color16 #fab387
+    /// color17 #f5e0dc
+    /// ";
+    /// let theme = TerminalTheme::new(&PALETTE.mocha, TerminalFormat::Kitty);
+    /// assert_eq!(theme.to_string(), upstream);
+    /// ```
+    Kitty,
+    /// A [WezTerm](https://wezterm.org/) TOML color scheme, as in
+    /// [catppuccin/wezterm](https://github.com/catppuccin/wezterm).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{TerminalFormat, TerminalTheme, PALETTE};
+    ///
+    /// let upstream = r##"[colors]
+    /// foreground = "#cdd6f4"
+    /// background = "#1e1e2e"
+    /// cursor_bg = "#f5e0dc"
+    /// cursor_border = "#f5e0dc"
+    /// cursor_fg = "#11111b"
+    /// selection_bg = "#585b70"
+    /// selection_fg = "#cdd6f4"
+    /// scrollbar_thumb = "#585b70"
+    /// split = "#6c7086"
+    /// visual_bell = "#313244"
+    /// compose_cursor = "#f2cdcd"
+    /// ansi = ["#45475a", "#f38ba8", "#a6e3a1", "#f9e2af", "#89b4fa", "#f5c2e7", "#94e2d5", "#a6adc8"]
+    /// brights = ["#585b70", "#f37799", "#89d88b", "#ebd391", "#74a8fc", "#f2aede", "#6bd7ca", "#bac2de"]
+    ///
+    /// [colors.indexed]
+    /// 16 = "#fab387"
+    /// 17 = "#f5e0dc"
+    ///
+    /// [colors.tab_bar]
+    /// background = "#11111b"
+    /// inactive_tab_edge = "#313244"
+    ///
+    /// [colors.tab_bar.active_tab]
+    /// bg_color = "#cba6f7"
+    /// fg_color = "#11111b"
+    ///
+    /// [colors.tab_bar.inactive_tab]
+    /// bg_color = "#181825"
+    /// fg_color = "#cdd6f4"
+    ///
+    /// [colors.tab_bar.inactive_tab_hover]
+    /// bg_color = "#1e1e2e"
+    /// fg_color = "#cdd6f4"
+    ///
+    /// [colors.tab_bar.new_tab]
+    /// bg_color = "#313244"
+    /// fg_color = "#cdd6f4"
+    ///
+    /// [colors.tab_bar.new_tab_hover]
+    /// bg_color = "#45475a"
+    /// fg_color = "#cdd6f4"
+    ///
+    /// [metadata]
+    /// name = "Catppuccin Mocha"
+    /// author = "Catppuccin Org"
+    /// origin_url = "https://github.com/catppuccin/wezterm"
+    /// "##;
+    /// let theme = TerminalTheme::new(&PALETTE.mocha, TerminalFormat::WezTerm);
+    /// assert_eq!(theme.to_string(), upstream);
+    /// ```
+    WezTerm,
+    /// A [foot](https://codeberg.org/dnkl/foot) `.ini` configuration file, as in
+    /// [catppuccin/foot](https://github.com/catppuccin/foot).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{TerminalFormat, TerminalTheme, PALETTE};
+    ///
+    /// let upstream = "[cursor]
+    /// color=11111b f5e0dc
+    ///
+    /// [colors]
+    /// foreground=cdd6f4
+    /// background=1e1e2e
+    ///
+    /// regular0=45475a
+    /// regular1=f38ba8
+    /// regular2=a6e3a1
+    /// regular3=f9e2af
+    /// regular4=89b4fa
+    /// regular5=f5c2e7
+    /// regular6=94e2d5
+    /// regular7=a6adc8
+    ///
+    /// bright0=585b70
+    /// bright1=f37799
+    /// bright2=89d88b
+    /// bright3=ebd391
+    /// bright4=74a8fc
+    /// bright5=f2aede
+    /// bright6=6bd7ca
+    /// bright7=bac2de
+    ///
+    /// 16=fab387
+    /// 17=f5e0dc
+    ///
+    /// selection-foreground=cdd6f4
+    /// selection-background=414356
+    ///
+    /// search-box-no-match=11111b f38ba8
+    /// search-box-match=cdd6f4 313244
+    ///
+    /// jump-labels=11111b fab387
+    /// urls=89b4fa
+    /// ";
+    /// let theme = TerminalTheme::new(&PALETTE.mocha, TerminalFormat::Foot);
+    /// assert_eq!(theme.to_string(), upstream);
+    /// ```
+    Foot,
+    /// A [Ghostty](https://ghostty.org/) theme file, as in
+    /// [catppuccin/ghostty](https://github.com/catppuccin/ghostty).
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{TerminalFormat, TerminalTheme, PALETTE};
+    ///
+    /// let upstream = "palette = 0=#45475a
+    /// palette = 1=#f38ba8
+    /// palette = 2=#a6e3a1
+    /// palette = 3=#f9e2af
+    /// palette = 4=#89b4fa
+    /// palette = 5=#f5c2e7
+    /// palette = 6=#94e2d5
+    /// palette = 7=#a6adc8
+    /// palette = 8=#585b70
+    /// palette = 9=#f37799
+    /// palette = 10=#89d88b
+    /// palette = 11=#ebd391
+    /// palette = 12=#74a8fc
+    /// palette = 13=#f2aede
+    /// palette = 14=#6bd7ca
+    /// palette = 15=#bac2de
+    /// background = #1e1e2e
+    /// foreground = #cdd6f4
+    /// cursor-color = #f5e0dc
+    /// cursor-text = #11111b
+    /// selection-background = #353749
+    /// selection-foreground = #cdd6f4
+    /// split-divider-color = #313244
+    /// ";
+    /// let theme = TerminalTheme::new(&PALETTE.mocha, TerminalFormat::Ghostty);
+    /// assert_eq!(theme.to_string(), upstream);
+    /// ```
+    Ghostty,
+}
+
+impl TerminalFormat {
+    /// Get the file extension of the format, without a leading dot.
+    #[must_use]
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Alacritty | Self::WezTerm => "toml",
+            Self::Kitty | Self::Ghostty => "conf",
+            Self::Foot => "ini",
+        }
+    }
+}
+
+/// Exports a flavor as a terminal emulator theme.
+///
+/// Themes use the flavor's [`ansi_colors`](Flavor::ansi_colors) for the 16
+/// terminal colors and `text` on `base` for the foreground and background. The
+/// cursor is `rosewater`, and the remaining colors follow each official port; see
+/// [`TerminalFormat`] for the exact output of each format.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{TerminalFormat, TerminalTheme, PALETTE};
+///
+/// let alacritty = TerminalTheme::new(&PALETTE.mocha, TerminalFormat::Alacritty).to_string();
+/// assert!(alacritty.starts_with(
+///     "[colors.primary]\nbackground = \"#1e1e2e\"\nforeground = \"#cdd6f4\"\n"
+/// ));
+///
+/// let ghostty = TerminalTheme::new(&PALETTE.latte, TerminalFormat::Ghostty).to_string();
+/// assert!(ghostty.starts_with("palette = 0=#5c5f77\npalette = 1=#d20f39\n"));
+/// assert!(ghostty.contains("\ncursor-color = #dc8a78\n"));
+/// assert!(ghostty.contains("\nselection-background = #d8dae1\n"));
+///
+/// let foot = TerminalTheme::new(&PALETTE.frappe, TerminalFormat::Foot).to_string();
+/// assert!(foot.contains("\nselection-background=4f5369\n"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct TerminalTheme<'a> {
+    /// The flavor to export.
+    pub flavor: &'a Flavor,
+    /// The output format.
+    pub format: TerminalFormat,
+}
+
+impl<'a> TerminalTheme<'a> {
+    /// Create a terminal theme exporter for the given flavor in the given format.
+    #[must_use]
+    pub const fn new(flavor: &'a Flavor, format: TerminalFormat) -> Self {
+        Self { flavor, format }
+    }
+
+    fn write_alacritty(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = &self.flavor.colors;
+        writeln!(f, "[colors.primary]")?;
+        writeln!(f, "background = \"{}\"", c.base.hex)?;
+        writeln!(f, "foreground = \"{}\"", c.text.hex)?;
+        writeln!(f, "dim_foreground = \"{}\"", c.overlay1.hex)?;
+        writeln!(f, "bright_foreground = \"{}\"", c.text.hex)?;
+        for (table, cursor) in [("cursor", c.rosewater), ("vi_mode_cursor", c.lavender)] {
+            writeln!(f, "\n[colors.{table}]")?;
+            writeln!(f, "text = \"{}\"", c.base.hex)?;
+            writeln!(f, "cursor = \"{}\"", cursor.hex)?;
+        }
+        for (table, background) in [
+            ("search.matches", c.subtext0),
+            ("search.focused_match", c.green),
+            ("footer_bar", c.subtext0),
+            ("hints.start", c.yellow),
+            ("hints.end", c.subtext0),
+        ] {
+            writeln!(f, "\n[colors.{table}]")?;
+            writeln!(f, "foreground = \"{}\"", c.base.hex)?;
+            writeln!(f, "background = \"{}\"", background.hex)?;
+        }
+        writeln!(f, "\n[colors.selection]")?;
+        writeln!(f, "text = \"{}\"", c.base.hex)?;
+        writeln!(f, "background = \"{}\"", c.rosewater.hex)?;
+        for (table, bright) in [("normal", false), ("bright", true), ("dim", false)] {
+            writeln!(f, "\n[colors.{table}]")?;
+            for pair in &self.flavor.ansi_colors.all_pairs() {
+                let color = if bright { pair.bright } else { pair.normal };
+                writeln!(f, "{} = \"{}\"", pair.name.identifier(), color.hex)?;
+            }
+        }
+        for (index, color) in [(16, c.peach), (17, c.rosewater)] {
+            writeln!(f, "\n[[colors.indexed_colors]]")?;
+            writeln!(f, "index = {index}")?;
+            writeln!(f, "color = \"{}\"", color.hex)?;
+        }
+        Ok(())
+    }
+
+    fn write_kitty(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = &self.flavor.colors;
+        let dark = self.flavor.dark;
+        writeln!(f, "# vim:ft=kitty\n")?;
+        writeln!(f, "## name:     Catppuccin Kitty {}", self.flavor.name)?;
+        writeln!(f, "## author:   Catppuccin Org")?;
+        writeln!(f, "## license:  MIT")?;
+        writeln!(
+            f,
+            "## upstream: https://github.com/catppuccin/kitty/blob/main/themes/{}.conf",
+            self.flavor.identifier()
+        )?;
+        writeln!(
+            f,
+            "## blurb:    Soothing pastel theme for the high-spirited!\n\n\n"
+        )?;
+        writeln!(f, "# The basic colors")?;
+        writeln!(f, "foreground              {}", c.text.hex)?;
+        writeln!(f, "background              {}", c.base.hex)?;
+        writeln!(f, "selection_foreground    {}", c.base.hex)?;
+        writeln!(f, "selection_background    {}", c.rosewater.hex)?;
+        writeln!(f, "\n# Cursor colors")?;
+        writeln!(f, "cursor                  {}", c.rosewater.hex)?;
+        writeln!(f, "cursor_text_color       {}", c.base.hex)?;
+        writeln!(f, "\n# URL underline color when hovering with mouse")?;
+        writeln!(f, "url_color               {}", c.rosewater.hex)?;
+        writeln!(f, "\n# Kitty window border colors")?;
+        writeln!(f, "active_border_color     {}", c.lavender.hex)?;
+        writeln!(f, "inactive_border_color   {}", c.overlay0.hex)?;
+        writeln!(f, "bell_border_color       {}", c.yellow.hex)?;
+        writeln!(f, "\n# OS Window titlebar colors")?;
+        writeln!(f, "wayland_titlebar_color system")?;
+        writeln!(f, "macos_titlebar_color system")?;
+        // the tab bar of the light flavor is darker than its background
+        let (active_tab, inactive_tab, tab_bar) = if dark {
+            (c.crust, c.mantle, c.crust)
+        } else {
+            (c.base, c.overlay0, c.surface1)
+        };
+        writeln!(f, "\n# Tab bar colors")?;
+        writeln!(f, "active_tab_foreground   {}", active_tab.hex)?;
+        writeln!(f, "active_tab_background   {}", c.mauve.hex)?;
+        writeln!(f, "inactive_tab_foreground {}", c.text.hex)?;
+        writeln!(f, "inactive_tab_background {}", inactive_tab.hex)?;
+        writeln!(f, "tab_bar_background      {}", tab_bar.hex)?;
+        writeln!(f, "\n# Colors for marks (marked text in the terminal)")?;
+        for (mark, color) in [(1, c.lavender), (2, c.mauve), (3, c.sapphire)] {
+            writeln!(f, "mark{mark}_foreground {}", c.base.hex)?;
+            writeln!(f, "mark{mark}_background {}", color.hex)?;
+        }
+        writeln!(f, "\n# The 16 terminal colors")?;
+        for pair in &self.flavor.ansi_colors.all_pairs() {
+            writeln!(f, "\n# {}", pair.name.identifier())?;
+            for color in [pair.normal, pair.bright] {
+                // align the colors of the pair
+                let padding = if color.code < 10 && pair.bright.code >= 10 {
+                    " "
+                } else {
+                    ""
+                };
+                writeln!(f, "color{}{padding} {}", color.code, color.hex)?;
+            }
+        }
+        writeln!(f, "\n# extended colors")?;
+        writeln!(f, "color16 {}", c.peach.hex)?;
+        writeln!(f, "color17 {}", c.rosewater.hex)
+    }
+
+    fn write_wezterm(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = &self.flavor.colors;
+        writeln!(f, "[colors]")?;
+        for (key, color) in [
+            ("foreground", c.text),
+            ("background", c.base),
+            ("cursor_bg", c.rosewater),
+            ("cursor_border", c.rosewater),
+            ("cursor_fg", c.crust),
+            ("selection_bg", c.surface2),
+            ("selection_fg", c.text),
+            ("scrollbar_thumb", c.surface2),
+            ("split", c.overlay0),
+            ("visual_bell", c.surface0),
+            ("compose_cursor", c.flamingo),
+        ] {
+            writeln!(f, "{key} = \"{}\"", color.hex)?;
+        }
+        let colors = self.flavor.ansi_colors.all_ansi_colors();
+        let (normal, bright) = colors.split_at(8);
+        for (key, colors) in [("ansi", normal), ("brights", bright)] {
+            write!(f, "{key} = [")?;
+            for (index, color) in colors.iter().enumerate() {
+                let separator = if index == 0 { "" } else { ", " };
+                write!(f, "{separator}\"{}\"", color.hex)?;
+            }
+            writeln!(f, "]")?;
+        }
+        writeln!(f, "\n[colors.indexed]")?;
+        writeln!(f, "16 = \"{}\"", c.peach.hex)?;
+        writeln!(f, "17 = \"{}\"", c.rosewater.hex)?;
+        writeln!(f, "\n[colors.tab_bar]")?;
+        writeln!(f, "background = \"{}\"", c.crust.hex)?;
+        writeln!(f, "inactive_tab_edge = \"{}\"", c.surface0.hex)?;
+        for (tab, background, foreground) in [
+            ("active_tab", c.mauve, c.crust),
+            ("inactive_tab", c.mantle, c.text),
+            ("inactive_tab_hover", c.base, c.text),
+            ("new_tab", c.surface0, c.text),
+            ("new_tab_hover", c.surface1, c.text),
+        ] {
+            writeln!(f, "\n[colors.tab_bar.{tab}]")?;
+            writeln!(f, "bg_color = \"{}\"", background.hex)?;
+            writeln!(f, "fg_color = \"{}\"", foreground.hex)?;
+        }
+        writeln!(f, "\n[metadata]")?;
+        writeln!(f, "name = \"Catppuccin {}\"", self.flavor.name)?;
+        writeln!(f, "author = \"Catppuccin Org\"")?;
+        writeln!(f, "origin_url = \"https://github.com/catppuccin/wezterm\"")
+    }
+
+    fn write_foot(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = &self.flavor.colors;
+        writeln!(f, "[cursor]")?;
+        writeln!(f, "color={} {}", Bare(c.crust.hex), Bare(c.rosewater.hex))?;
+        writeln!(f, "\n[colors]")?;
+        writeln!(f, "foreground={}", Bare(c.text.hex))?;
+        writeln!(f, "background={}\n", Bare(c.base.hex))?;
+        for (key, bright) in [("regular", false), ("bright", true)] {
+            for pair in &self.flavor.ansi_colors.all_pairs() {
+                let color = if bright { pair.bright } else { pair.normal };
+                writeln!(f, "{key}{}={}", color.code % 8, Bare(color.hex))?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "16={}", Bare(c.peach.hex))?;
+        writeln!(f, "17={}\n", Bare(c.rosewater.hex))?;
+        writeln!(f, "selection-foreground={}", Bare(c.text.hex))?;
+        let selection = blend(c.base.rgb, c.overlay2.rgb, 77);
+        writeln!(f, "selection-background={}\n", Bare(selection))?;
+        writeln!(
+            f,
+            "search-box-no-match={} {}",
+            Bare(c.crust.hex),
+            Bare(c.red.hex)
+        )?;
+        writeln!(
+            f,
+            "search-box-match={} {}\n",
+            Bare(c.text.hex),
+            Bare(c.surface0.hex)
+        )?;
+        writeln!(f, "jump-labels={} {}", Bare(c.crust.hex), Bare(c.peach.hex))?;
+        writeln!(f, "urls={}", Bare(c.blue.hex))
+    }
+
+    fn write_ghostty(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = &self.flavor.colors;
+        for AnsiColor { code, hex, .. } in self.flavor.ansi_colors.all_ansi_colors() {
+            writeln!(f, "palette = {code}={hex}")?;
+        }
+        writeln!(f, "background = {}", c.base.hex)?;
+        writeln!(f, "foreground = {}", c.text.hex)?;
+        writeln!(f, "cursor-color = {}", c.rosewater.hex)?;
+        writeln!(f, "cursor-text = {}", c.crust.hex)?;
+        let selection = blend(c.base.rgb, c.overlay2.rgb, 51);
+        writeln!(f, "selection-background = {selection}")?;
+        writeln!(f, "selection-foreground = {}", c.text.hex)?;
+        writeln!(f, "split-divider-color = {}", c.surface0.hex)
+    }
+}
+
+impl fmt::Display for TerminalTheme<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.format {
+            TerminalFormat::Alacritty => self.write_alacritty(f),
+            TerminalFormat::Kitty => self.write_kitty(f),
+            TerminalFormat::WezTerm => self.write_wezterm(f),
+            TerminalFormat::Foot => self.write_foot(f),
+            TerminalFormat::Ghostty => self.write_ghostty(f),
+        }
+    }
+}
+
+/// Blends `over` onto `under` with an opacity out of 255.
+///
+/// Each weighted channel is rounded separately, as by the template engine of
+/// the official ports, so that the result matches their files exactly.
+fn blend(under: Rgb, over: Rgb, opacity: u8) -> Hex {
+    // rounds to the nearest integer
+    let weighted = |channel: u8, weight: u8| (u16::from(channel) * u16::from(weight) + 127) / 255;
+    let channel = |under: u8, over: u8| {
+        u8::try_from(weighted(under, u8::MAX - opacity) + weighted(over, opacity))
+            .unwrap_or(u8::MAX)
+    };
+    Hex(Rgb {
+        r: channel(under.r, over.r),
+        g: channel(under.g, over.g),
+        b: channel(under.b, over.b),
+    })
+}
+
+/// Displays a hex color without its leading hash.
+struct Bare(Hex);
+
+impl fmt::Display for Bare {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Rgb { r, g, b } = self.0 .0;
+        write!(f, "{r:02x}{g:02x}{b:02x}")
+    }
+}
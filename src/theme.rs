@@ -0,0 +1,759 @@
+//! Themes: a base flavor with overridden colors and semantic roles, loadable from
+//! theme files in TOML, YAML or any other format supported by serde.
+use core::{fmt, ops::Index};
+
+use crate::{Color, ColorName, Flavor, FlavorName};
+
+/// A semantic role in a user interface, such as the background or links.
+///
+/// Each role is mapped to one of a flavor's colors by a [`Theme`], defaulting to
+/// the color recommended by the
+/// [Catppuccin style guide](https://github.com/catppuccin/catppuccin/blob/main/docs/style-guide.md).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    /// The background pane; `base` by default.
+    Background,
+    /// Secondary panes, such as sidebars; `mantle` by default.
+    SecondaryPane,
+    /// Surface elements, such as buttons; `surface0` by default.
+    Surface,
+    /// Overlays, such as popups; `overlay0` by default.
+    Overlay,
+    /// Body copy; `text` by default.
+    Text,
+    /// Main headlines; `text` by default.
+    Headline,
+    /// Sub-headlines and labels; `subtext0` by default.
+    Subheadline,
+    /// Subtle text, such as comments; `overlay1` by default.
+    Subtle,
+    /// Text on an accent-colored background; `base` by default.
+    OnAccent,
+    /// Links and URLs; `blue` by default.
+    Link,
+    /// Success messages; `green` by default.
+    Success,
+    /// Warnings; `yellow` by default.
+    Warning,
+    /// Errors; `red` by default.
+    Error,
+    /// Tags and pills; `blue` by default.
+    Tag,
+    /// The selection background, typically at 20-30% opacity; `overlay2` by default.
+    Selection,
+    /// The cursor; `rosewater` by default.
+    Cursor,
+    /// The border of the active window; `lavender` by default.
+    ActiveBorder,
+    /// The borders of inactive windows; `overlay0` by default.
+    InactiveBorder,
+}
+
+impl Role {
+    /// All roles, in declaration order.
+    pub const ALL: [Self; 18] = [
+        Self::Background,
+        Self::SecondaryPane,
+        Self::Surface,
+        Self::Overlay,
+        Self::Text,
+        Self::Headline,
+        Self::Subheadline,
+        Self::Subtle,
+        Self::OnAccent,
+        Self::Link,
+        Self::Success,
+        Self::Warning,
+        Self::Error,
+        Self::Tag,
+        Self::Selection,
+        Self::Cursor,
+        Self::ActiveBorder,
+        Self::InactiveBorder,
+    ];
+
+    /// Get the role's identifier; the `snake_case` key used in theme files.
+    #[must_use]
+    pub const fn identifier(&self) -> &'static str {
+        match self {
+            Self::Background => "background",
+            Self::SecondaryPane => "secondary_pane",
+            Self::Surface => "surface",
+            Self::Overlay => "overlay",
+            Self::Text => "text",
+            Self::Headline => "headline",
+            Self::Subheadline => "subheadline",
+            Self::Subtle => "subtle",
+            Self::OnAccent => "on_accent",
+            Self::Link => "link",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Tag => "tag",
+            Self::Selection => "selection",
+            Self::Cursor => "cursor",
+            Self::ActiveBorder => "active_border",
+            Self::InactiveBorder => "inactive_border",
+        }
+    }
+
+    /// Get the color the style guide recommends for the role.
+    #[must_use]
+    pub const fn default_color(&self) -> ColorName {
+        match self {
+            Self::Background | Self::OnAccent => ColorName::Base,
+            Self::SecondaryPane => ColorName::Mantle,
+            Self::Surface => ColorName::Surface0,
+            Self::Overlay | Self::InactiveBorder => ColorName::Overlay0,
+            Self::Text | Self::Headline => ColorName::Text,
+            Self::Subheadline => ColorName::Subtext0,
+            Self::Subtle => ColorName::Overlay1,
+            Self::Link | Self::Tag => ColorName::Blue,
+            Self::Success => ColorName::Green,
+            Self::Warning => ColorName::Yellow,
+            Self::Error => ColorName::Red,
+            Self::Selection => ColorName::Overlay2,
+            Self::Cursor => ColorName::Rosewater,
+            Self::ActiveBorder => ColorName::Lavender,
+        }
+    }
+}
+
+/// A flavor with semantic [`Role`]s mapped to its colors, typically loaded from a
+/// theme file.
+///
+/// With the `serde` feature, themes can be deserialized from theme files such as
+/// the following, in TOML, YAML, JSON or any other format supported by serde:
+///
+/// ```toml
+/// # the base flavor, by identifier
+/// flavor = "mocha"
+///
+/// # colors of the base flavor to override, by identifier
+/// [colors]
+/// base = "#11111b"                    # hex, with 3 or 6 digits
+/// mantle = "rgb(10, 10, 20)"          # CSS rgb()
+/// text = "hsl(226deg, 64%, 88%)"      # CSS hsl()
+/// crust = "mantle"                    # another color of the base flavor
+/// mauve = "latte.mauve"               # a color path, as accepted by `ColorRef`
+/// surface0 = { r = 49, g = 50, b = 68 }
+/// surface1 = { h = 234, s = 0.13, l = 0.31 }
+///
+/// # semantic roles, mapped to colors by identifier
+/// [roles]
+/// link = "sapphire"
+/// cursor = "mauve"
+/// ```
+///
+/// Colors given by name or path refer to the original palette, not to other
+/// overrides. Unknown keys, colors and roles are rejected with an error naming
+/// the closest match. [`ThemeSchema`] describes the format as a JSON Schema for
+/// editor validation; add a `$schema` key pointing to it in YAML and JSON files.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{ColorName, Role, Theme, PALETTE};
+///
+/// let mut theme = Theme::new(PALETTE.mocha);
+/// assert_eq!(theme[Role::Link], PALETTE.mocha.colors.blue);
+///
+/// theme.set_role(Role::Link, ColorName::Sapphire);
+/// assert_eq!(theme.role(Role::Link), ColorName::Sapphire);
+/// assert_eq!(theme[Role::Link], PALETTE.mocha.colors.sapphire);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    /// The flavor, including any overridden colors.
+    pub flavor: Flavor,
+    roles: [ColorName; Role::ALL.len()],
+}
+
+impl Theme {
+    /// Create a theme for the given flavor, with every role mapped to its
+    /// [default color](Role::default_color).
+    #[must_use]
+    pub const fn new(flavor: Flavor) -> Self {
+        let mut roles = [ColorName::Base; Role::ALL.len()];
+        let mut index = 0;
+        while index < roles.len() {
+            roles[index] = Role::ALL[index].default_color();
+            index += 1;
+        }
+        Self { flavor, roles }
+    }
+
+    /// Get the name of the color a role is mapped to.
+    #[must_use]
+    pub const fn role(&self, role: Role) -> ColorName {
+        self.roles[role as usize]
+    }
+
+    /// Map a role to one of the flavor's colors.
+    pub const fn set_role(&mut self, role: Role, name: ColorName) {
+        self.roles[role as usize] = name;
+    }
+
+    /// Get the color a role is mapped to.
+    ///
+    /// This is equivalent to using the index operator, but can also be used in
+    /// const contexts.
+    #[must_use]
+    pub const fn get_role_color(&self, role: Role) -> &Color {
+        self.flavor.get_color(self.role(role))
+    }
+}
+
+impl Index<Role> for Theme {
+    type Output = Color;
+
+    fn index(&self, index: Role) -> &Self::Output {
+        self.get_role_color(index)
+    }
+}
+
+impl From<Theme> for Flavor {
+    fn from(value: Theme) -> Self {
+        value.flavor
+    }
+}
+
+/// Writes a [JSON Schema](https://json-schema.org/) of the theme file format
+/// described on [`Theme`], for validation and completion in editors.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::ThemeSchema;
+///
+/// let schema = ThemeSchema.to_string();
+/// assert!(schema.contains(r#""flavor": { "enum": ["latte", "frappe", "macchiato", "mocha"] }"#));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ThemeSchema;
+
+impl fmt::Display for ThemeSchema {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(
+            f,
+            "  \"$schema\": \"https://json-schema.org/draft/2020-12/schema\","
+        )?;
+        writeln!(f, "  \"title\": \"Catppuccin theme\",")?;
+        writeln!(
+            f,
+            "  \"description\": \"A Catppuccin flavor with overridden colors and semantic roles.\","
+        )?;
+        writeln!(f, "  \"type\": \"object\",")?;
+        writeln!(f, "  \"required\": [\"flavor\"],")?;
+        writeln!(f, "  \"additionalProperties\": false,")?;
+        writeln!(f, "  \"properties\": {{")?;
+        writeln!(f, "    \"$schema\": {{ \"type\": \"string\" }},")?;
+        write!(f, "    \"flavor\": {{ \"enum\": [")?;
+        write_list(f, FlavorName::ALL.iter().map(FlavorName::identifier))?;
+        writeln!(f, "] }},")?;
+        writeln!(f, "    \"colors\": {{")?;
+        writeln!(f, "      \"type\": \"object\",")?;
+        writeln!(f, "      \"additionalProperties\": false,")?;
+        writeln!(f, "      \"properties\": {{")?;
+        for (index, name) in ColorName::ALL.iter().enumerate() {
+            let comma = if index + 1 < ColorName::ALL.len() {
+                ","
+            } else {
+                ""
+            };
+            writeln!(
+                f,
+                "        \"{}\": {{ \"$ref\": \"#/$defs/color\" }}{comma}",
+                name.identifier()
+            )?;
+        }
+        writeln!(f, "      }}")?;
+        writeln!(f, "    }},")?;
+        writeln!(f, "    \"roles\": {{")?;
+        writeln!(f, "      \"type\": \"object\",")?;
+        writeln!(f, "      \"additionalProperties\": false,")?;
+        writeln!(f, "      \"properties\": {{")?;
+        for (index, role) in Role::ALL.iter().enumerate() {
+            let comma = if index + 1 < Role::ALL.len() { "," } else { "" };
+            writeln!(
+                f,
+                "        \"{}\": {{ \"$ref\": \"#/$defs/colorName\", \"default\": \"{}\" }}{comma}",
+                role.identifier(),
+                role.default_color().identifier()
+            )?;
+        }
+        writeln!(f, "      }}")?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "  }},")?;
+        writeln!(f, "  \"$defs\": {{")?;
+        write!(f, "    \"colorName\": {{ \"enum\": [")?;
+        write_list(f, ColorName::ALL.iter().map(ColorName::identifier))?;
+        writeln!(f, "] }},")?;
+        write_color_schema(f)?;
+        writeln!(f, "  }}")?;
+        writeln!(f, "}}")
+    }
+}
+
+/// Write the schema of a color override, as the `color` definition.
+fn write_color_schema(f: &mut fmt::Formatter) -> fmt::Result {
+    writeln!(f, "    \"color\": {{")?;
+    writeln!(f, "      \"anyOf\": [")?;
+    writeln!(f, "        {{ \"$ref\": \"#/$defs/colorName\" }},")?;
+    // escaped for JSON
+    for pattern in [
+        r"^#([0-9a-fA-F]{3}|[0-9a-fA-F]{6})$",
+        r"^rgb\\(.*\\)$",
+        r"^hsl\\(.*\\)$",
+        r"^[a-z]+\\.[a-z0-9_.]+$",
+    ] {
+        writeln!(
+            f,
+            "        {{ \"type\": \"string\", \"pattern\": \"{pattern}\" }},"
+        )?;
+    }
+    writeln!(f, "        {{")?;
+    writeln!(f, "          \"type\": \"object\",")?;
+    writeln!(f, "          \"required\": [\"r\", \"g\", \"b\"],")?;
+    writeln!(f, "          \"additionalProperties\": false,")?;
+    writeln!(f, "          \"properties\": {{")?;
+    for (channel, comma) in [("r", ","), ("g", ","), ("b", "")] {
+        writeln!(
+            f,
+            "            \"{channel}\": {{ \"type\": \"integer\", \"minimum\": 0, \"maximum\": 255 }}{comma}"
+        )?;
+    }
+    writeln!(f, "          }}")?;
+    writeln!(f, "        }},")?;
+    writeln!(f, "        {{")?;
+    writeln!(f, "          \"type\": \"object\",")?;
+    writeln!(f, "          \"required\": [\"h\", \"s\", \"l\"],")?;
+    writeln!(f, "          \"additionalProperties\": false,")?;
+    writeln!(f, "          \"properties\": {{")?;
+    writeln!(f, "            \"h\": {{ \"type\": \"number\" }},")?;
+    for (channel, comma) in [("s", ","), ("l", "")] {
+        writeln!(
+            f,
+            "            \"{channel}\": {{ \"type\": \"number\", \"minimum\": 0, \"maximum\": 1 }}{comma}"
+        )?;
+    }
+    writeln!(f, "          }}")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "      ]")?;
+    writeln!(f, "    }}")?;
+    Ok(())
+}
+
+/// Write quoted strings separated by commas.
+fn write_list<'a>(f: &mut fmt::Formatter, items: impl Iterator<Item = &'a str>) -> fmt::Result {
+    for (index, item) in items.enumerate() {
+        let separator = if index == 0 { "" } else { ", " };
+        write!(f, "{separator}\"{item}\"")?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+mod _serde {
+    use core::fmt;
+
+    use serde::{
+        de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+        Deserialize, Deserializer,
+    };
+
+    use super::{Role, Theme};
+    use crate::{
+        parse::{DidYouMean, KeySeed},
+        ColorName, ColorRef, FlavorName, Hex, Hsl, Rgb, PALETTE,
+    };
+
+    /// A color override, as written in a theme file.
+    #[derive(Clone, Copy)]
+    enum ColorValue {
+        Rgb(Rgb),
+        Hsl(Hsl),
+        Name(ColorName),
+        Path(ColorRef),
+    }
+
+    /// Deserializes a theme file, as described on [`Theme`].
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use catppuccin::{Role, Theme, PALETTE};
+    ///
+    /// let theme: Theme = toml::from_str(
+    ///     r##"
+    ///         flavor = "mocha"
+    ///
+    ///         [colors]
+    ///         base = "#000"
+    ///         text = { r = 255, g = 255, b = 255 }
+    ///         mauve = "latte.mauve"
+    ///
+    ///         [roles]
+    ///         link = "sapphire"
+    ///     "##,
+    /// )?;
+    /// assert_eq!(theme.flavor.colors.base.hex.to_string(), "#000000");
+    /// assert_eq!(theme[Role::Text].hex.to_string(), "#ffffff");
+    /// assert_eq!(theme.flavor.colors.mauve.hex, PALETTE.latte.colors.mauve.hex);
+    /// assert_eq!(theme[Role::Link], PALETTE.mocha.colors.sapphire);
+    ///
+    /// let theme: Theme = serde_yaml::from_str(
+    ///     "
+    ///     flavor: latte
+    ///     colors:
+    ///       crust: hsl(220deg, 22%, 92%)
+    ///     ",
+    /// )?;
+    /// assert_eq!(theme.flavor.colors.crust.rgb, PALETTE.latte.colors.mantle.rgb);
+    ///
+    /// let error = toml::from_str::<Theme>("flavor = \"mocha\"\n[colors]\nmauv = \"#fff\"")
+    ///     .unwrap_err();
+    /// assert!(error.message().starts_with("unknown color `mauv` (did you mean `mauve`?)"));
+    ///
+    /// let error = serde_yaml::from_str::<Theme>("flavor: mocha\ncolors:\n  base: '#12345'")
+    ///     .unwrap_err();
+    /// assert!(error.to_string().starts_with(
+    ///     "colors.base: invalid hex color `#12345` for `base`, expected 3 or 6 hex digits"
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    impl<'de> Deserialize<'de> for Theme {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(ThemeVisitor)
+        }
+    }
+
+    struct ThemeVisitor;
+
+    impl<'de> Visitor<'de> for ThemeVisitor {
+        type Value = Theme;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a theme with a base flavor, colors and roles")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            const KEYS: [&str; 4] = ["$schema", "flavor", "colors", "roles"];
+            let mut flavor = None;
+            let mut colors = None;
+            let mut roles = None;
+            while let Some(index) = map.next_key_seed(KeySeed {
+                kind: "key",
+                identifiers: KEYS,
+            })? {
+                let duplicate = match index {
+                    0 => map.next_value::<IgnoredAny>().map(|_| false)?,
+                    1 => flavor
+                        .replace(map.next_value_seed(KeySeed {
+                            kind: "flavor",
+                            identifiers: FlavorName::ALL.map(|name| name.identifier()),
+                        })?)
+                        .is_some(),
+                    2 => colors
+                        .replace(map.next_value::<ColorOverrides>()?)
+                        .is_some(),
+                    _ => roles.replace(map.next_value::<RoleColors>()?).is_some(),
+                };
+                if duplicate {
+                    return Err(de::Error::duplicate_field(KEYS[index]));
+                }
+            }
+
+            let flavor = flavor.ok_or_else(|| de::Error::missing_field("flavor"))?;
+            let base = PALETTE.get_flavor(FlavorName::ALL[flavor]);
+            let mut theme = Theme::new(*base);
+            for (name, value) in ColorName::ALL.into_iter().zip(colors.unwrap_or_default().0) {
+                let Some(value) = value else { continue };
+                let color = &mut theme.flavor.colors[name];
+                (color.hex, color.rgb, color.hsl) = match value {
+                    ColorValue::Rgb(rgb) => (Hex(rgb), rgb, Hsl::from(rgb)),
+                    ColorValue::Hsl(hsl) => (Hex(Rgb::from(hsl)), Rgb::from(hsl), hsl),
+                    ColorValue::Name(name) => {
+                        let color = base.colors[name];
+                        (color.hex, color.rgb, color.hsl)
+                    }
+                    ColorValue::Path(path) => {
+                        let color = path.resolve(&PALETTE);
+                        (color.hex(), color.rgb(), color.hsl())
+                    }
+                };
+            }
+            for (role, name) in Role::ALL.into_iter().zip(roles.unwrap_or_default().0) {
+                if let Some(name) = name {
+                    theme.set_role(role, name);
+                }
+            }
+            Ok(theme)
+        }
+    }
+
+    /// The `colors` table of a theme file.
+    #[derive(Default)]
+    struct ColorOverrides([Option<ColorValue>; ColorName::ALL.len()]);
+
+    impl<'de> Deserialize<'de> for ColorOverrides {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(ColorOverridesVisitor)
+        }
+    }
+
+    struct ColorOverridesVisitor;
+
+    impl<'de> Visitor<'de> for ColorOverridesVisitor {
+        type Value = ColorOverrides;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map of colors")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let identifiers = ColorName::ALL.map(|name| name.identifier());
+            let mut overrides = ColorOverrides::default();
+            while let Some(index) = map.next_key_seed(KeySeed {
+                kind: "color",
+                identifiers,
+            })? {
+                let value = map.next_value_seed(ColorValueSeed(ColorName::ALL[index]))?;
+                if overrides.0[index].replace(value).is_some() {
+                    return Err(de::Error::duplicate_field(identifiers[index]));
+                }
+            }
+            Ok(overrides)
+        }
+    }
+
+    /// The `roles` table of a theme file.
+    #[derive(Default)]
+    struct RoleColors([Option<ColorName>; Role::ALL.len()]);
+
+    impl<'de> Deserialize<'de> for RoleColors {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(RoleColorsVisitor)
+        }
+    }
+
+    struct RoleColorsVisitor;
+
+    impl<'de> Visitor<'de> for RoleColorsVisitor {
+        type Value = RoleColors;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map of roles to color identifiers")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let identifiers = Role::ALL.map(|role| role.identifier());
+            let mut roles = RoleColors::default();
+            while let Some(index) = map.next_key_seed(KeySeed {
+                kind: "role",
+                identifiers,
+            })? {
+                let name = ColorName::ALL[map.next_value_seed(KeySeed {
+                    kind: "color",
+                    identifiers: ColorName::ALL.map(|name| name.identifier()),
+                })?];
+                if roles.0[index].replace(name).is_some() {
+                    return Err(de::Error::duplicate_field(identifiers[index]));
+                }
+            }
+            Ok(roles)
+        }
+    }
+
+    /// Deserializes the override of the given color.
+    struct ColorValueSeed(ColorName);
+
+    impl<'de> DeserializeSeed<'de> for ColorValueSeed {
+        type Value = ColorValue;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for ColorValueSeed {
+        type Value = ColorValue;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f,
+                "a hex, rgb() or hsl() color, a color identifier or path, \
+                 or a table of r, g and b or h, s and l channels"
+            )
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let name = self.0.identifier();
+            if let Some(digits) = v.strip_prefix('#') {
+                return parse_hex(digits).map(ColorValue::Rgb).ok_or_else(|| {
+                    E::custom(format_args!(
+                        "invalid hex color `{v}` for `{name}`, expected 3 or 6 hex digits"
+                    ))
+                });
+            }
+            if let Some(args) = function_args(v, "rgb") {
+                return parse_rgb(args).map(ColorValue::Rgb).ok_or_else(|| {
+                    E::custom(format_args!(
+                        "invalid color `{v}` for `{name}`, expected rgb() with three channels from 0 to 255"
+                    ))
+                });
+            }
+            if let Some(args) = function_args(v, "hsl") {
+                return parse_hsl(args).map(ColorValue::Hsl).ok_or_else(|| {
+                    E::custom(format_args!(
+                        "invalid color `{v}` for `{name}`, expected hsl() with a hue and two percentages"
+                    ))
+                });
+            }
+            if v.contains('.') {
+                return v.parse().map(ColorValue::Path).map_err(|error| {
+                    E::custom(format_args!(
+                        "invalid color path `{v}` for `{name}`: {error}"
+                    ))
+                });
+            }
+            v.parse::<ColorName>()
+                .ok()
+                .filter(|color| color.identifier() == v)
+                .map(ColorValue::Name)
+                .ok_or_else(|| {
+                    let suggestion = ColorName::parse_with_suggestion(v)
+                        .map_or_else(|error| error.suggestion(), |color| Some(color.identifier()));
+                    E::custom(format_args!(
+                        "invalid color `{v}` for `{name}`, expected a hex, rgb() or hsl() color, \
+                         or a color identifier or path{}",
+                        DidYouMean(suggestion)
+                    ))
+                })
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            const CHANNELS: [&str; 6] = ["r", "g", "b", "h", "s", "l"];
+            let mut rgb = [None; 3];
+            let mut hsl = [None; 3];
+            while let Some(index) = map.next_key_seed(KeySeed {
+                kind: "channel",
+                identifiers: CHANNELS,
+            })? {
+                let duplicate = if index < 3 {
+                    rgb[index].replace(map.next_value::<u8>()?).is_some()
+                } else {
+                    hsl[index - 3].replace(map.next_value::<f64>()?).is_some()
+                };
+                if duplicate {
+                    return Err(de::Error::duplicate_field(CHANNELS[index]));
+                }
+            }
+            match (rgb, hsl) {
+                ([Some(r), Some(g), Some(b)], [None, None, None]) => {
+                    Ok(ColorValue::Rgb(Rgb { r, g, b }))
+                }
+                ([None, None, None], [Some(h), Some(s), Some(l)])
+                    if h.is_finite() && (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&l) =>
+                {
+                    Ok(ColorValue::Hsl(Hsl { h, s, l }))
+                }
+                _ => Err(de::Error::custom(format_args!(
+                    "invalid color for `{}`, expected either `r`, `g` and `b` channels from 0 \
+                     to 255, or `h`, `s` and `l` channels with `s` and `l` from 0 to 1",
+                    self.0.identifier()
+                ))),
+            }
+        }
+    }
+
+    /// Parse 3 or 6 hex digits.
+    fn parse_hex(digits: &str) -> Option<Rgb> {
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let [_, high, middle, low] = u32::from_str_radix(digits, 16).ok()?.to_be_bytes();
+        match digits.len() {
+            3 => Some(Rgb {
+                r: middle * 17,
+                g: (low >> 4) * 17,
+                b: (low & 0xf) * 17,
+            }),
+            6 => Some(Rgb {
+                r: high,
+                g: middle,
+                b: low,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Get the arguments of a CSS function call such as `rgb(1, 2, 3)`, if `s` is
+    /// a call to `function`.
+    fn function_args<'a>(s: &'a str, function: &str) -> Option<&'a str> {
+        s.strip_prefix(function)?
+            .trim_start()
+            .strip_prefix('(')?
+            .strip_suffix(')')
+    }
+
+    /// Split exactly three function arguments, separated by commas or whitespace.
+    fn three_args(args: &str) -> Option<[&str; 3]> {
+        let mut args = args
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|arg| !arg.is_empty());
+        let three = [args.next()?, args.next()?, args.next()?];
+        args.next().is_none().then_some(three)
+    }
+
+    fn parse_rgb(args: &str) -> Option<Rgb> {
+        let [r, g, b] = three_args(args)?;
+        Some(Rgb {
+            r: r.parse().ok()?,
+            g: g.parse().ok()?,
+            b: b.parse().ok()?,
+        })
+    }
+
+    fn parse_hsl(args: &str) -> Option<Hsl> {
+        let [h, s, l] = three_args(args)?;
+        let h: f64 = h.strip_suffix("deg").unwrap_or(h).parse().ok()?;
+        let percentage = |value: &str| {
+            let value = value.strip_suffix('%')?.parse::<f64>().ok()? / 100.0;
+            (0.0..=1.0).contains(&value).then_some(value)
+        };
+        h.is_finite().then_some(Hsl {
+            h,
+            s: percentage(s)?,
+            l: percentage(l)?,
+        })
+    }
+}
@@ -0,0 +1,538 @@
+//! Export and import of the palette as design tokens.
+use core::fmt;
+
+use crate::{Flavor, Palette};
+
+/// The design token formats supported by [`DesignTokens`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DesignTokenFormat {
+    /// The [W3C Design Tokens Community Group](https://www.designtokens.org/) format.
+    ///
+    /// Each flavor is a group of `color` tokens, split into `accent`,
+    /// `monochromatic` and `ansi` groups.
+    Dtcg,
+    /// [Style Dictionary](https://styledictionary.com/) source JSON.
+    ///
+    /// Each color is a token at `color.<flavor>.<color>`, and each ANSI color a
+    /// token at `color.<flavor>.ansi.<ansi color>`.
+    StyleDictionary,
+}
+
+/// Exports a [`Palette`] as design tokens in JSON.
+///
+/// Design tokens can be imported again with [`Flavor::from_design_tokens`] or
+/// [`Flavor::from_style_dictionary`], depending on the format.
+///
+/// Example:
+///
+/// ```rust
+/// use catppuccin::{DesignTokenFormat, DesignTokens, PALETTE};
+///
+/// let tokens = DesignTokens::new(&PALETTE, DesignTokenFormat::Dtcg).to_string();
+/// assert!(tokens.starts_with("{\n  \"latte\": {\n    \"$type\": \"color\",\n"));
+/// assert!(tokens.contains(r##""rosewater": { "$value": "#dc8a78", "$description": "Rosewater" }"##));
+///
+/// let tokens = DesignTokens::new(&PALETTE, DesignTokenFormat::StyleDictionary).to_string();
+/// assert!(tokens.contains(r##""rosewater": { "value": "#dc8a78", "type": "color" }"##));
+/// ```
+#[derive(Clone, Copy)]
+pub struct DesignTokens<'a> {
+    /// The palette to export.
+    pub palette: &'a Palette,
+    /// The output format.
+    pub format: DesignTokenFormat,
+}
+
+impl<'a> DesignTokens<'a> {
+    /// Create a design token exporter for the given palette in the given format.
+    #[must_use]
+    pub const fn new(palette: &'a Palette, format: DesignTokenFormat) -> Self {
+        Self { palette, format }
+    }
+
+    fn write_dtcg_flavor(f: &mut fmt::Formatter, flavor: &Flavor) -> fmt::Result {
+        writeln!(f, "    \"$type\": \"color\",")?;
+        writeln!(f, "    \"$description\": \"Catppuccin {}\",", flavor.name)?;
+        for (group, accent) in [("accent", true), ("monochromatic", false)] {
+            writeln!(f, "    \"{group}\": {{")?;
+            let colors = flavor.iter().filter(|color| color.accent == accent);
+            write_entries(f, "      ", colors, |f, color| {
+                write!(
+                    f,
+                    "\"{}\": {{ \"$value\": \"{}\", \"$description\": \"{}\" }}",
+                    color.identifier(),
+                    color.hex,
+                    color.name
+                )
+            })?;
+            writeln!(f, "    }},")?;
+        }
+        writeln!(f, "    \"ansi\": {{")?;
+        write_entries(f, "      ", flavor.ansi_colors.iter(), |f, color| {
+            write!(
+                f,
+                "\"{}\": {{ \"$value\": \"{}\", \"$description\": \"{}\" }}",
+                color.name.identifier(),
+                color.hex,
+                color.name
+            )
+        })?;
+        writeln!(f, "    }}")
+    }
+
+    fn write_style_dictionary_flavor(f: &mut fmt::Formatter, flavor: &Flavor) -> fmt::Result {
+        for color in flavor {
+            let (name, hex) = (color.identifier(), color.hex);
+            writeln!(
+                f,
+                "      \"{name}\": {{ \"value\": \"{hex}\", \"type\": \"color\" }},"
+            )?;
+        }
+        writeln!(f, "      \"ansi\": {{")?;
+        write_entries(f, "        ", flavor.ansi_colors.iter(), |f, color| {
+            let (name, hex) = (color.name.identifier(), color.hex);
+            write!(
+                f,
+                "\"{name}\": {{ \"value\": \"{hex}\", \"type\": \"color\" }}"
+            )
+        })?;
+        writeln!(f, "      }}")
+    }
+}
+
+impl fmt::Display for DesignTokens<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{{")?;
+        match self.format {
+            DesignTokenFormat::Dtcg => {
+                write_entries(f, "  ", self.palette.iter(), |f, flavor| {
+                    writeln!(f, "\"{}\": {{", flavor.identifier())?;
+                    Self::write_dtcg_flavor(f, flavor)?;
+                    write!(f, "  }}")
+                })?;
+            }
+            DesignTokenFormat::StyleDictionary => {
+                writeln!(f, "  \"color\": {{")?;
+                write_entries(f, "    ", self.palette.iter(), |f, flavor| {
+                    writeln!(f, "\"{}\": {{", flavor.identifier())?;
+                    Self::write_style_dictionary_flavor(f, flavor)?;
+                    write!(f, "    }}")
+                })?;
+                writeln!(f, "  }}")?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Write each item on its own line at the given indent, separated by commas.
+fn write_entries<T>(
+    f: &mut fmt::Formatter,
+    indent: &str,
+    items: impl Iterator<Item = T>,
+    mut write_item: impl FnMut(&mut fmt::Formatter, T) -> fmt::Result,
+) -> fmt::Result {
+    let mut items = items.peekable();
+    while let Some(item) = items.next() {
+        write!(f, "{indent}")?;
+        write_item(f, item)?;
+        let comma = if items.peek().is_some() { "," } else { "" };
+        writeln!(f, "{comma}")?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+mod _serde {
+    use core::fmt;
+
+    use serde::{
+        de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+        Deserialize, Deserializer,
+    };
+
+    use crate::{parse::DidYouMean, AnsiColorName, ColorName, DesignTokenFormat, Flavor, Hex, Hsl};
+
+    impl Flavor {
+        /// Import a flavor from design tokens in the
+        /// [W3C Design Tokens Community Group](https://www.designtokens.org/) format,
+        /// as written by [`DesignTokens`](crate::DesignTokens).
+        ///
+        /// The colors in the top-level token group named `group` replace those in
+        /// `base`; colors missing from the group keep their values from `base`.
+        /// Tokens are matched to colors by identifier, and may be nested in the
+        /// `accent` and `monochromatic` groups; ANSI colors are read from the
+        /// `ansi` group. Design tokens only describe colors, so the name, emoji,
+        /// order and `dark` flag of the flavor are those of `base`.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// use catppuccin::{DesignTokenFormat, DesignTokens, Flavor, PALETTE};
+        ///
+        /// let tokens = r##"{
+        ///     "americano": {
+        ///         "$type": "color",
+        ///         "monochromatic": {
+        ///             "base": { "$value": "#000000" },
+        ///             "mantle": { "$value": "#0a0a0a" }
+        ///         }
+        ///     }
+        /// }"##;
+        /// let deserializer = &mut serde_json::Deserializer::from_str(tokens);
+        /// let americano = Flavor::from_design_tokens(deserializer, "americano", &PALETTE.mocha)?;
+        /// assert_eq!(americano.colors.base.hex.to_string(), "#000000");
+        /// assert_eq!(americano.colors.mauve, PALETTE.mocha.colors.mauve);
+        /// assert_eq!(americano.name, PALETTE.mocha.name);
+        ///
+        /// // the exported tokens round-trip
+        /// let tokens = DesignTokens::new(&PALETTE, DesignTokenFormat::Dtcg).to_string();
+        /// let deserializer = &mut serde_json::Deserializer::from_str(&tokens);
+        /// let frappe = Flavor::from_design_tokens(deserializer, "frappe", &PALETTE.mocha)?;
+        /// assert!(frappe.iter().zip(&PALETTE.frappe).all(|(a, b)| a.rgb == b.rgb));
+        /// assert!(frappe
+        ///     .ansi_colors
+        ///     .iter()
+        ///     .zip(PALETTE.frappe.ansi_colors.iter())
+        ///     .all(|(a, b)| a.rgb == b.rgb));
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the tokens are malformed, contain a token that is
+        /// not a color of the flavor, or do not contain the group.
+        pub fn from_design_tokens<'de, D>(
+            deserializer: D,
+            group: &str,
+            base: &Self,
+        ) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            FileSeed {
+                path: &[group],
+                format: DesignTokenFormat::Dtcg,
+                flavor: *base,
+            }
+            .deserialize(deserializer)
+        }
+
+        /// Import a flavor from [Style Dictionary](https://styledictionary.com/)
+        /// source JSON, as written by [`DesignTokens`](crate::DesignTokens).
+        ///
+        /// The tokens at `color.<flavor>.<color>` replace the colors in `base`,
+        /// and those at `color.<flavor>.ansi.<ansi color>` its ANSI colors;
+        /// colors missing from the file keep their values from `base`. As with
+        /// [`Flavor::from_design_tokens`], the name, emoji, order and `dark` flag
+        /// of the flavor are those of `base`.
+        ///
+        /// Example:
+        ///
+        /// ```rust
+        /// use catppuccin::{DesignTokenFormat, DesignTokens, Flavor, PALETTE};
+        ///
+        /// let tokens = r##"{
+        ///     "color": {
+        ///         "americano": {
+        ///             "base": { "value": "#000000", "type": "color" },
+        ///             "ansi": { "black": { "value": "#0a0a0a" } }
+        ///         }
+        ///     }
+        /// }"##;
+        /// let deserializer = &mut serde_json::Deserializer::from_str(tokens);
+        /// let americano = Flavor::from_style_dictionary(deserializer, "americano", &PALETTE.mocha)?;
+        /// assert_eq!(americano.colors.base.hex.to_string(), "#000000");
+        /// assert_eq!(americano.ansi_colors.black.hex.to_string(), "#0a0a0a");
+        /// assert_eq!(americano.colors.mauve, PALETTE.mocha.colors.mauve);
+        ///
+        /// // the exported tokens round-trip
+        /// let tokens = DesignTokens::new(&PALETTE, DesignTokenFormat::StyleDictionary).to_string();
+        /// let deserializer = &mut serde_json::Deserializer::from_str(&tokens);
+        /// let latte = Flavor::from_style_dictionary(deserializer, "latte", &PALETTE.mocha)?;
+        /// assert!(latte.iter().zip(&PALETTE.latte).all(|(a, b)| a.rgb == b.rgb));
+        /// assert!(latte
+        ///     .ansi_colors
+        ///     .iter()
+        ///     .zip(PALETTE.latte.ansi_colors.iter())
+        ///     .all(|(a, b)| a.rgb == b.rgb));
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the tokens are malformed, contain a token that is
+        /// not a color of the flavor, or do not contain the flavor.
+        pub fn from_style_dictionary<'de, D>(
+            deserializer: D,
+            flavor: &str,
+            base: &Self,
+        ) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            FileSeed {
+                path: &["color", flavor],
+                format: DesignTokenFormat::StyleDictionary,
+                flavor: *base,
+            }
+            .deserialize(deserializer)
+        }
+    }
+
+    /// Follows a path of nested groups to the group containing the flavor.
+    struct FileSeed<'a> {
+        path: &'a [&'a str],
+        format: DesignTokenFormat,
+        flavor: Flavor,
+    }
+
+    impl<'de> DeserializeSeed<'de> for FileSeed<'_> {
+        type Value = Flavor;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for FileSeed<'_> {
+        type Value = Flavor;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map of design token groups")
+        }
+
+        fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let Some((&group, rest)) = self.path.split_first() else {
+                return Err(de::Error::custom("empty token group path"));
+            };
+            let mut found = false;
+            while let Some(matches) = map.next_key_seed(KeyMatches(group))? {
+                if matches && !found {
+                    if rest.is_empty() {
+                        map.next_value_seed(GroupSeed {
+                            flavor: &mut self.flavor,
+                            format: self.format,
+                            ansi: false,
+                        })?;
+                    } else {
+                        self.flavor = map.next_value_seed(FileSeed {
+                            path: rest,
+                            format: self.format,
+                            flavor: self.flavor,
+                        })?;
+                    }
+                    found = true;
+                } else {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+            if found {
+                Ok(self.flavor)
+            } else {
+                Err(de::Error::custom(format_args!(
+                    "missing token group `{group}`"
+                )))
+            }
+        }
+    }
+
+    /// Deserializes a map key, yielding whether it is equal to the given string.
+    struct KeyMatches<'a>(&'a str);
+
+    impl<'de> DeserializeSeed<'de> for KeyMatches<'_> {
+        type Value = bool;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl Visitor<'_> for KeyMatches<'_> {
+        type Value = bool;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(v == self.0)
+        }
+    }
+
+    /// A key in a token group; either a property such as `$type`, a nested group,
+    /// or a token named after a color.
+    enum GroupKey {
+        Property,
+        Group { ansi: bool },
+        Color(ColorName),
+        AnsiColor(AnsiColorName),
+    }
+
+    /// Deserializes a [`GroupKey`] in the `ansi` group if `ansi` is true, or in
+    /// any other group otherwise.
+    struct GroupKeySeed {
+        format: DesignTokenFormat,
+        ansi: bool,
+    }
+
+    impl<'de> DeserializeSeed<'de> for GroupKeySeed {
+        type Value = GroupKey;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl Visitor<'_> for GroupKeySeed {
+        type Value = GroupKey;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a token or group name")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if self.format == DesignTokenFormat::Dtcg && v.starts_with('$') {
+                return Ok(GroupKey::Property);
+            }
+            // parse strictly, so that keys match the exported identifiers
+            let suggestion = if self.ansi {
+                if let Some(name) = AnsiColorName::ALL.into_iter().find(|n| n.identifier() == v) {
+                    return Ok(GroupKey::AnsiColor(name));
+                }
+                AnsiColorName::parse_with_suggestion(v)
+                    .err()
+                    .and_then(|e| e.suggestion())
+            } else {
+                match (self.format, v) {
+                    (DesignTokenFormat::Dtcg, "accent" | "monochromatic") => {
+                        return Ok(GroupKey::Group { ansi: false })
+                    }
+                    (_, "ansi") => return Ok(GroupKey::Group { ansi: true }),
+                    _ => {}
+                }
+                if let Some(name) = ColorName::ALL.into_iter().find(|n| n.identifier() == v) {
+                    return Ok(GroupKey::Color(name));
+                }
+                ColorName::parse_with_suggestion(v)
+                    .err()
+                    .and_then(|e| e.suggestion())
+            };
+            Err(E::custom(format_args!(
+                "unknown token `{v}`{}",
+                DidYouMean(suggestion)
+            )))
+        }
+    }
+
+    /// Deserializes a token group, and any nested groups, into a flavor.
+    ///
+    /// Colors whose value is unchanged keep their HSL channels from the base
+    /// flavor, rather than recomputing them from RGB.
+    struct GroupSeed<'a> {
+        flavor: &'a mut Flavor,
+        format: DesignTokenFormat,
+        ansi: bool,
+    }
+
+    impl GroupSeed<'_> {
+        fn next_token<'de, A>(&self, map: &mut A) -> Result<Hex, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            Ok(match self.format {
+                DesignTokenFormat::Dtcg => map.next_value::<Token>()?.value,
+                DesignTokenFormat::StyleDictionary => {
+                    map.next_value::<StyleDictionaryToken>()?.value
+                }
+            })
+        }
+    }
+
+    impl<'de> DeserializeSeed<'de> for GroupSeed<'_> {
+        type Value = ();
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for GroupSeed<'_> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a design token group")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let key_seed = || GroupKeySeed {
+                format: self.format,
+                ansi: self.ansi,
+            };
+            while let Some(key) = map.next_key_seed(key_seed())? {
+                match key {
+                    GroupKey::Property => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                    GroupKey::Group { ansi } => map.next_value_seed(GroupSeed {
+                        flavor: self.flavor,
+                        format: self.format,
+                        ansi,
+                    })?,
+                    GroupKey::Color(name) => {
+                        let value = self.next_token(&mut map)?;
+                        let color = &mut self.flavor.colors[name];
+                        if color.hex != value {
+                            (color.hex, color.rgb, color.hsl) =
+                                (value, value.0, Hsl::from(value.0));
+                        }
+                    }
+                    GroupKey::AnsiColor(name) => {
+                        let value = self.next_token(&mut map)?;
+                        let color = &mut self.flavor.ansi_colors[name];
+                        if color.hex != value {
+                            (color.hex, color.rgb, color.hsl) =
+                                (value, value.0, Hsl::from(value.0));
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Token {
+        #[serde(rename = "$value")]
+        value: Hex,
+    }
+
+    #[derive(Deserialize)]
+    struct StyleDictionaryToken {
+        value: Hex,
+    }
+}